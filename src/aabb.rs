@@ -0,0 +1,277 @@
+use crate::prelude::*;
+
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::mat3::*;
+use crate::mat4::*;
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Aabb2<T> {
+	pub min: Vec2<T>,
+	pub max: Vec2<T>,
+}
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Aabb3<T> {
+	pub min: Vec3<T>,
+	pub max: Vec3<T>,
+}
+
+pub fn aabb2<T>(min: Vec2<T>, max: Vec2<T>) -> Aabb2<T> {
+	Aabb2{ min, max }
+}
+
+pub fn aabb3<T>(min: Vec3<T>, max: Vec3<T>) -> Aabb3<T> {
+	Aabb3{ min, max }
+}
+
+//a <= b on every axis
+fn le<T: PartialOrd, const N: usize>(a: [T; N], b: [T; N]) -> bool {
+	a.iter().zip(b.iter()).all(|(a, b)| a <= b)
+}
+
+impl<T> Aabb2<T> {
+	//the smallest box holding every point, None if there are no points
+	pub fn from_points<I: IntoIterator<Item=Vec2<T>>>(points: I) -> Option<Self>
+		where T: Copy + PartialOrd {
+		let mut i = points.into_iter();
+		let first = i.next()?;
+		Some(i.fold(aabb2(first, first), |b, p| aabb2(b.min.min(p), b.max.max(p))))
+	}
+	
+	pub fn union(self, other: Self) -> Self
+		where T: PartialOrd {
+		aabb2(self.min.min(other.min), self.max.max(other.max))
+	}
+	
+	//None if the boxes don't overlap, touching boxes give a flat box
+	pub fn intersection(self, other: Self) -> Option<Self>
+		where T: Copy + PartialOrd {
+		let b = aabb2(self.min.max(other.min), self.max.min(other.max));
+		if b.is_empty() { None } else { Some(b) }
+	}
+	
+	//true if min is above max on any axis (or either is nan)
+	pub fn is_empty(self) -> bool
+		where T: Copy + PartialOrd {
+		!le(self.min.into_array(), self.max.into_array())
+	}
+	
+	//points on the surface count as inside
+	pub fn contains_point(self, p: Vec2<T>) -> bool
+		where T: PartialOrd + Copy {
+		le(self.min.into_array(), p.into_array()) && le(p.into_array(), self.max.into_array())
+	}
+	
+	pub fn contains_box(self, other: Self) -> bool
+		where T: PartialOrd + Copy {
+		le(self.min.into_array(), other.min.into_array()) && le(other.max.into_array(), self.max.into_array())
+	}
+	
+	pub fn overlaps(self, other: Self) -> bool
+		where T: PartialOrd + Copy {
+		le(self.min.into_array(), other.max.into_array()) && le(other.min.into_array(), self.max.into_array())
+	}
+	
+	pub fn center(self) -> Vec2<T>
+		where T: Copy + Two + Add<Output=T> + Div<Output=T> {
+		(self.min + self.max) / T::two()
+	}
+	
+	//the full size of the box along each axis
+	pub fn extents(self) -> Vec2<T>
+		where T: Sub<Output=T> {
+		self.max - self.min
+	}
+	
+	pub fn half_extents(self) -> Vec2<T>
+		where T: Copy + Two + Sub<Output=T> + Div<Output=T> {
+		self.extents() / T::two()
+	}
+	
+	//grows the box by amount on every side, a negative amount shrinks it
+	pub fn expand(self, amount: T) -> Self
+		where T: Copy + Add<Output=T> + Sub<Output=T> {
+		aabb2(self.min - amount, self.max + amount)
+	}
+	
+	pub fn include_point(self, p: Vec2<T>) -> Self
+		where T: Copy + PartialOrd {
+		aabb2(self.min.min(p), self.max.max(p))
+	}
+	
+	//p itself if it's inside the box
+	pub fn closest_point(self, p: Vec2<T>) -> Vec2<T>
+		where T: PartialOrd {
+		p.clamp(self.min, self.max)
+	}
+	
+	pub fn distance_to_point(self, p: Vec2<T>) -> T
+		where T: Copy + PartialOrd + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		(self.closest_point(p) - p).magnitude()
+	}
+	
+	pub fn area(self) -> T
+		where T: Sub<Output=T> + Mul<Output=T> {
+		self.extents().mul_elem()
+	}
+	
+	pub fn perimeter(self) -> T
+		where T: Two + Sub<Output=T> + Mul<Output=T> + Add<Output=T> {
+		self.extents().sum_elem() * T::two()
+	}
+	
+	pub fn corners(self) -> [Vec2<T>; 4]
+		where T: Copy {
+		let Aabb2{ min,max } = self;
+		[min, vec2(max.x, min.y), vec2(min.x, max.y), max]
+	}
+	
+	//the tight box around the transformed box, m is a 2d homogeneous transform (translation in the last column)
+	//this goes through the matrix elements directly instead of transforming all the corners
+	pub fn transform(self, m: Mat3<T>) -> Self
+		where T: Copy + PartialOrd + Mul<Output=T> + Add<Output=T> {
+		let [r0, r1, _] = m.into_array();
+		let (lo, hi) = (self.min.into_array(), self.max.into_array());
+		let axis = |r: [T; 3]| {
+			let (mut a, mut b) = (r[2], r[2]);
+			for j in 0..2 {
+				let (e, f) = (r[j] * lo[j], r[j] * hi[j]);
+				a = a + e.non_nan_min(f);
+				b = b + e.non_nan_max(f);
+			}
+			(a, b)
+		};
+		let ((x0, x1), (y0, y1)) = (axis(r0), axis(r1));
+		aabb2(vec2(x0, y0), vec2(x1, y1))
+	}
+}
+
+impl<T> Aabb3<T> {
+	//the smallest box holding every point, None if there are no points
+	pub fn from_points<I: IntoIterator<Item=Vec3<T>>>(points: I) -> Option<Self>
+		where T: Copy + PartialOrd {
+		let mut i = points.into_iter();
+		let first = i.next()?;
+		Some(i.fold(aabb3(first, first), |b, p| aabb3(b.min.min(p), b.max.max(p))))
+	}
+	
+	pub fn union(self, other: Self) -> Self
+		where T: PartialOrd {
+		aabb3(self.min.min(other.min), self.max.max(other.max))
+	}
+	
+	//None if the boxes don't overlap, touching boxes give a flat box
+	pub fn intersection(self, other: Self) -> Option<Self>
+		where T: Copy + PartialOrd {
+		let b = aabb3(self.min.max(other.min), self.max.min(other.max));
+		if b.is_empty() { None } else { Some(b) }
+	}
+	
+	//true if min is above max on any axis (or either is nan)
+	pub fn is_empty(self) -> bool
+		where T: Copy + PartialOrd {
+		!le(self.min.into_array(), self.max.into_array())
+	}
+	
+	//points on the surface count as inside
+	pub fn contains_point(self, p: Vec3<T>) -> bool
+		where T: PartialOrd + Copy {
+		le(self.min.into_array(), p.into_array()) && le(p.into_array(), self.max.into_array())
+	}
+	
+	pub fn contains_box(self, other: Self) -> bool
+		where T: PartialOrd + Copy {
+		le(self.min.into_array(), other.min.into_array()) && le(other.max.into_array(), self.max.into_array())
+	}
+	
+	pub fn overlaps(self, other: Self) -> bool
+		where T: PartialOrd + Copy {
+		le(self.min.into_array(), other.max.into_array()) && le(other.min.into_array(), self.max.into_array())
+	}
+	
+	pub fn center(self) -> Vec3<T>
+		where T: Copy + Two + Add<Output=T> + Div<Output=T> {
+		(self.min + self.max) / T::two()
+	}
+	
+	//the full size of the box along each axis
+	pub fn extents(self) -> Vec3<T>
+		where T: Sub<Output=T> {
+		self.max - self.min
+	}
+	
+	pub fn half_extents(self) -> Vec3<T>
+		where T: Copy + Two + Sub<Output=T> + Div<Output=T> {
+		self.extents() / T::two()
+	}
+	
+	//grows the box by amount on every side, a negative amount shrinks it
+	pub fn expand(self, amount: T) -> Self
+		where T: Copy + Add<Output=T> + Sub<Output=T> {
+		aabb3(self.min - amount, self.max + amount)
+	}
+	
+	pub fn include_point(self, p: Vec3<T>) -> Self
+		where T: Copy + PartialOrd {
+		aabb3(self.min.min(p), self.max.max(p))
+	}
+	
+	//p itself if it's inside the box
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: PartialOrd {
+		p.clamp(self.min, self.max)
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		(self.closest_point(p) - p).magnitude()
+	}
+	
+	pub fn surface_area(self) -> T
+		where T: Copy + Two + Sub<Output=T> + Mul<Output=T> + Add<Output=T> {
+		let Vec3{ x,y,z } = self.extents();
+		(x * y + y * z + z * x) * T::two()
+	}
+	
+	pub fn volume(self) -> T
+		where T: Sub<Output=T> + Mul<Output=T> {
+		self.extents().mul_elem()
+	}
+	
+	pub fn corners(self) -> [Vec3<T>; 8]
+		where T: Copy {
+		let Aabb3{ min,max } = self;
+		[
+			min,
+			vec3(max.x, min.y, min.z),
+			vec3(min.x, max.y, min.z),
+			vec3(max.x, max.y, min.z),
+			vec3(min.x, min.y, max.z),
+			vec3(max.x, min.y, max.z),
+			vec3(min.x, max.y, max.z),
+			max,
+		]
+	}
+	
+	//the tight box around the transformed box, m is expected to be affine (bottom row 0 0 0 1)
+	//this goes through the matrix elements directly instead of transforming all the corners
+	pub fn transform(self, m: Mat4<T>) -> Self
+		where T: Copy + PartialOrd + Mul<Output=T> + Add<Output=T> {
+		let [r0, r1, r2, _] = m.into_array();
+		let (lo, hi) = (self.min.into_array(), self.max.into_array());
+		let axis = |r: [T; 4]| {
+			let (mut a, mut b) = (r[3], r[3]);
+			for j in 0..3 {
+				let (e, f) = (r[j] * lo[j], r[j] * hi[j]);
+				a = a + e.non_nan_min(f);
+				b = b + e.non_nan_max(f);
+			}
+			(a, b)
+		};
+		let ((x0, x1), (y0, y1), (z0, z1)) = (axis(r0), axis(r1), axis(r2));
+		aabb3(vec3(x0, y0, z0), vec3(x1, y1, z1))
+	}
+}
@@ -0,0 +1,323 @@
+use crate::prelude::*;
+
+//an angle in radians, there is no conversion from a bare T so the unit is always written out at the call
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,PartialOrd,Hash,Serialize,Deserialize)]
+pub struct Rad<T>(pub T);
+
+//an angle in degrees, it has to be wrapped to be passed where radians are expected
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,PartialOrd,Hash,Serialize,Deserialize)]
+pub struct Deg<T>(pub T);
+
+fn half_turn_deg<T: From<u8>>() -> T {
+	T::from(180)
+}
+
+//into [0, full)
+fn wrap<T>(x: T, full: T) -> T
+	where T: Copy + PartialOrd + Zero + Add<Output=T> + Rem<Output=T> {
+	let r = x % full;
+	let r = if r < T::zero() { r + full } else { r };
+	//a tiny negative r rounds up to full when it's added
+	if r >= full { T::zero() } else { r }
+}
+
+//into (-full / 2, full / 2]
+fn wrap_signed<T>(x: T, full: T) -> T
+	where T: Copy + PartialOrd + Zero + Two + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Rem<Output=T> {
+	let r = wrap(x, full);
+	if r > full / T::two() { r - full } else { r }
+}
+
+impl<T> Rad<T> {
+	pub fn zero() -> Self
+		where T: Zero {
+		Rad(T::zero())
+	}
+	
+	pub fn half_turn() -> Self
+		where T: Pi {
+		Rad(T::pi())
+	}
+	
+	pub fn full_turn() -> Self
+		where T: Pi + Two + Mul<Output=T> {
+		Rad(T::pi() * T::two())
+	}
+	
+	pub fn sin(self) -> T
+		where T: Trig {
+		self.0.sin()
+	}
+	
+	pub fn cos(self) -> T
+		where T: Trig {
+		self.0.cos()
+	}
+	
+	pub fn tan(self) -> T
+		where T: Trig {
+		self.0.tan()
+	}
+	
+	pub fn sin_cos(self) -> (T, T)
+		where T: Trig {
+		self.0.sin_cos()
+	}
+	
+	pub fn asin(x: T) -> Self
+		where T: Trig {
+		Rad(x.asin())
+	}
+	
+	pub fn acos(x: T) -> Self
+		where T: Trig {
+		Rad(x.acos())
+	}
+	
+	pub fn atan(x: T) -> Self
+		where T: Trig {
+		Rad(x.atan())
+	}
+	
+	//the angle of the point (x, y), in (-pi, pi]
+	pub fn atan2(y: T, x: T) -> Self
+		where T: Trig {
+		Rad(y.atan2(x))
+	}
+	
+	//into [0, 2pi)
+	pub fn normalize(self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + Pi + Add<Output=T> + Mul<Output=T> + Rem<Output=T> {
+		Rad(wrap(self.0, Self::full_turn().0))
+	}
+	
+	//into (-pi, pi]
+	pub fn normalize_signed(self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + Pi + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Rem<Output=T> {
+		Rad(wrap_signed(self.0, Self::full_turn().0))
+	}
+	
+	//how far to turn from self to reach other the shorter way round, in (-pi, pi]
+	pub fn shortest_difference(self, other: Self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + Pi + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Rem<Output=T> {
+		Rad(other.0 - self.0).normalize_signed()
+	}
+	
+	//turns the shorter way round from self towards other, the result is in (-pi, pi]
+	pub fn lerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + Two + Pi + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Rem<Output=T> {
+		Rad(self.0 + self.shortest_difference(other).0 * t).normalize_signed()
+	}
+}
+
+impl<T> Deg<T> {
+	pub fn zero() -> Self
+		where T: Zero {
+		Deg(T::zero())
+	}
+	
+	pub fn half_turn() -> Self
+		where T: From<u8> {
+		Deg(half_turn_deg())
+	}
+	
+	pub fn full_turn() -> Self
+		where T: From<u8> + Two + Mul<Output=T> {
+		Deg(half_turn_deg::<T>() * T::two())
+	}
+	
+	pub fn sin(self) -> T
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::from(self).sin()
+	}
+	
+	pub fn cos(self) -> T
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::from(self).cos()
+	}
+	
+	pub fn tan(self) -> T
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::from(self).tan()
+	}
+	
+	pub fn sin_cos(self) -> (T, T)
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::from(self).sin_cos()
+	}
+	
+	pub fn asin(x: T) -> Self
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::asin(x).into()
+	}
+	
+	pub fn acos(x: T) -> Self
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::acos(x).into()
+	}
+	
+	pub fn atan(x: T) -> Self
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::atan(x).into()
+	}
+	
+	//the angle of the point (x, y), in (-180, 180]
+	pub fn atan2(y: T, x: T) -> Self
+		where T: Copy + Trig + Pi + From<u8> + Mul<Output=T> + Div<Output=T> {
+		Rad::atan2(y, x).into()
+	}
+	
+	//into [0, 360), done in degrees so whole degrees stay exact
+	pub fn normalize(self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + From<u8> + Add<Output=T> + Mul<Output=T> + Rem<Output=T> {
+		Deg(wrap(self.0, Self::full_turn().0))
+	}
+	
+	//into (-180, 180]
+	pub fn normalize_signed(self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + From<u8> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Rem<Output=T> {
+		Deg(wrap_signed(self.0, Self::full_turn().0))
+	}
+	
+	//how far to turn from self to reach other the shorter way round, in (-180, 180]
+	pub fn shortest_difference(self, other: Self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + From<u8> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Rem<Output=T> {
+		Deg(other.0 - self.0).normalize_signed()
+	}
+	
+	//turns the shorter way round from self towards other, the result is in (-180, 180]
+	pub fn lerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + Two + From<u8> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Rem<Output=T> {
+		Deg(self.0 + self.shortest_difference(other).0 * t).normalize_signed()
+	}
+}
+
+impl<T> From<Deg<T>> for Rad<T>
+	where T: Pi + From<u8> + Mul<Output=T> + Div<Output=T>
+{
+	fn from(d: Deg<T>) -> Self {
+		Rad(d.0 * T::pi() / half_turn_deg())
+	}
+}
+
+impl<T> From<Rad<T>> for Deg<T>
+	where T: Pi + From<u8> + Mul<Output=T> + Div<Output=T>
+{
+	fn from(r: Rad<T>) -> Self {
+		Deg(r.0 * half_turn_deg() / T::pi())
+	}
+}
+
+macro impl_angle_ops($($A: ident),*) {
+	$(
+		impl<T> Default for $A<T>
+			where T: Zero
+		{
+			fn default() -> Self {
+				$A(T::zero())
+			}
+		}
+		
+		impl<T> Add<$A<T>> for $A<T>
+			where T: Add<Output=T>
+		{
+			type Output = Self;
+			
+			fn add(self, other: Self) -> Self {
+				$A(self.0 + other.0)
+			}
+		}
+		
+		impl<T> AddAssign<$A<T>> for $A<T>
+			where T: AddAssign<T>
+		{
+			fn add_assign(&mut self, other: Self) {
+				self.0 += other.0;
+			}
+		}
+		
+		impl<T> Sub<$A<T>> for $A<T>
+			where T: Sub<Output=T>
+		{
+			type Output = Self;
+			
+			fn sub(self, other: Self) -> Self {
+				$A(self.0 - other.0)
+			}
+		}
+		
+		impl<T> SubAssign<$A<T>> for $A<T>
+			where T: SubAssign<T>
+		{
+			fn sub_assign(&mut self, other: Self) {
+				self.0 -= other.0;
+			}
+		}
+		
+		impl<T> Mul<T> for $A<T>
+			where T: Mul<Output=T>
+		{
+			type Output = Self;
+			
+			fn mul(self, scalar: T) -> Self {
+				$A(self.0 * scalar)
+			}
+		}
+		
+		impl<T> Div<T> for $A<T>
+			where T: Div<Output=T>
+		{
+			type Output = Self;
+			
+			fn div(self, scalar: T) -> Self {
+				$A(self.0 / scalar)
+			}
+		}
+		
+		//the ratio of two angles
+		impl<T> Div<$A<T>> for $A<T>
+			where T: Div<Output=T>
+		{
+			type Output = T;
+			
+			fn div(self, other: Self) -> T {
+				self.0 / other.0
+			}
+		}
+		
+		impl<T: Neg> Neg for $A<T> {
+			type Output = $A<<T as Neg>::Output>;
+			fn neg(self) -> $A<<T as Neg>::Output> { $A(-self.0) }
+		}
+		
+		impl<T: Add<Output=T> + Zero> Sum<$A<T>> for $A<T> {
+			fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+				iter.fold($A(T::zero()), |a, b| a + b)
+			}
+		}
+	)*
+}
+
+impl_angle_ops!(Rad, Deg);
+
+impl<T: fmt::Display> fmt::Display for Rad<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if let Some(p) = f.precision() {
+			write!(f, "{:.*} rad", p, self.0)
+		} else {
+			write!(f, "{} rad", self.0)
+		}
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Deg<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if let Some(p) = f.precision() {
+			write!(f, "{:.*}°", p, self.0)
+		} else {
+			write!(f, "{}°", self.0)
+		}
+	}
+}
@@ -0,0 +1,379 @@
+use crate::prelude::*;
+
+use crate::vec2::*;
+use crate::mat2::*;
+use crate::angle::*;
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Complex<T> {
+	pub re: T,
+	pub im: T,
+}
+
+pub fn complex<T>(re: T, im: T) -> Complex<T> {
+	Complex { re, im }
+}
+
+impl<T> Complex<T> {
+	pub fn zero() -> Self
+		where T: Zero {
+		complex(T::zero(), T::zero())
+	}
+	
+	pub fn one() -> Self
+		where T: Zero + One {
+		complex(T::one(), T::zero())
+	}
+	
+	pub fn i() -> Self
+		where T: Zero + One {
+		complex(T::zero(), T::one())
+	}
+	
+	pub fn conj(self) -> Self
+		where T: Neg<Output=T> {
+		complex(self.re, -self.im)
+	}
+	
+	pub fn norm_squared(self) -> T
+		where T: Copy + Mul<Output=T> + Add<Output=T> {
+		self.re * self.re + self.im * self.im
+	}
+	
+	//the modulus
+	pub fn abs(self) -> T
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> {
+		self.norm_squared().sqrt()
+	}
+	
+	//the angle from the positive real axis, in (-pi, pi]
+	pub fn arg(self) -> T
+		where T: Copy + Trig {
+		self.im.atan2(self.re)
+	}
+	
+	pub fn from_polar(r: T, theta: T) -> Self
+		where T: Copy + Trig + Mul<Output=T> {
+		let (s, c) = theta.sin_cos();
+		complex(r * c, r * s)
+	}
+	
+	//(abs, arg)
+	pub fn to_polar(self) -> (T, T)
+		where T: Copy + Sqrt<T> + Trig + Mul<Output=T> + Add<Output=T> {
+		(self.abs(), self.arg())
+	}
+	
+	pub fn exp(self) -> Self
+		where T: Copy + Trig + Exp + Mul<Output=T> {
+		Self::from_polar(self.re.exp(), self.im)
+	}
+	
+	//the principal branch, the imaginary part is the arg
+	pub fn ln(self) -> Self
+		where T: Copy + Sqrt<T> + Trig + Exp + Mul<Output=T> + Add<Output=T> {
+		complex(self.abs().ln(), self.arg())
+	}
+	
+	//principal value of self^n, zero stays zero for positive n
+	pub fn powf(self, n: T) -> Self
+		where T: Copy + Sqrt<T> + Trig + Exp + Mul<Output=T> + Add<Output=T> {
+		let (r, theta) = self.to_polar();
+		Self::from_polar((r.ln() * n).exp(), theta * n)
+	}
+	
+	//the principal root, with a non negative real part
+	//computed without going through polar form so real and imaginary inputs give exact results
+	pub fn sqrt(self) -> Self
+		where T: Copy + PartialOrd + Zero + Two + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		let t = ((self.abs() + abs(self.re)) / T::two()).sqrt();
+		if t == T::zero() { return Self::zero(); }
+		if self.re >= T::zero() {
+			complex(t, self.im / (T::two() * t))
+		} else {
+			let im = if self.im < T::zero() { -t } else { t };
+			complex(abs(self.im) / (T::two() * t), im)
+		}
+	}
+	
+	//1 / self
+	pub fn inv(self) -> Self
+		where T: Copy + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		self.conj() / self.norm_squared()
+	}
+}
+
+impl<T> Default for Complex<T>
+	where T: Zero
+{
+	fn default() -> Self {
+		Self::zero()
+	}
+}
+
+impl<T> Add<Complex<T>> for Complex<T>
+	where T: Add<Output=T>
+{
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		complex(self.re + other.re, self.im + other.im)
+	}
+}
+
+impl<T> Sub<Complex<T>> for Complex<T>
+	where T: Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn sub(self, other: Self) -> Self {
+		complex(self.re - other.re, self.im - other.im)
+	}
+}
+
+impl<T> Mul<Complex<T>> for Complex<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		complex(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+	}
+}
+
+impl<T> Div<Complex<T>> for Complex<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T>
+{
+	type Output = Self;
+	
+	fn div(self, other: Self) -> Self {
+		let d = other.re * other.re + other.im * other.im;
+		complex((self.re * other.re + self.im * other.im) / d, (self.im * other.re - self.re * other.im) / d)
+	}
+}
+
+impl<T> Mul<T> for Complex<T>
+	where T: Copy + Mul<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, scalar: T) -> Self {
+		complex(self.re * scalar, self.im * scalar)
+	}
+}
+
+impl<T> Div<T> for Complex<T>
+	where T: Copy + Div<Output=T>
+{
+	type Output = Self;
+	
+	fn div(self, scalar: T) -> Self {
+		complex(self.re / scalar, self.im / scalar)
+	}
+}
+
+impl<T> AddAssign<Complex<T>> for Complex<T>
+	where T: AddAssign<T>
+{
+	fn add_assign(&mut self, other: Self) {
+		self.re += other.re;
+		self.im += other.im;
+	}
+}
+
+impl<T> SubAssign<Complex<T>> for Complex<T>
+	where T: SubAssign<T>
+{
+	fn sub_assign(&mut self, other: Self) {
+		self.re -= other.re;
+		self.im -= other.im;
+	}
+}
+
+impl<T> MulAssign<Complex<T>> for Complex<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+impl<T> DivAssign<Complex<T>> for Complex<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T>
+{
+	fn div_assign(&mut self, other: Self) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Neg> Neg for Complex<T> {
+	type Output = Complex<<T as Neg>::Output>;
+	fn neg(self) -> Complex<<T as Neg>::Output> { complex(-self.re, -self.im) }
+}
+
+impl<T> ArrayTuple for Complex<T> {
+	type Array = [T; 2];
+	type Tuple = (T,T);
+	fn into_array(self) -> [T; 2] { let Complex{re,im}=self; [re,im] }
+	fn into_tuple(self) -> (T,T) { let Complex{re,im}=self; (re,im) }
+}
+
+impl<T: fmt::Display> fmt::Display for Complex<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if let Some(p) = f.precision() {
+			write!(f, "{2:.*} + {3:.*}i", p, p, self.re, self.im)
+		} else {
+			write!(f, "{} + {}i", self.re, self.im)
+		}
+	}
+}
+
+impl<T: NiceFmt> NiceFmt for Complex<T> {
+	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+		format!("({} + {}i)", self.re.nice_fmt(limit, pad), self.im.nice_fmt(limit, pad))
+	}
+}
+
+impl<T: fmt::LowerExp> fmt::LowerExp for Complex<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if let Some(p) = f.precision() {
+			write!(f, "{2:.*e} + {3:.*e}i", p, p, self.re, self.im)
+		} else {
+			write!(f, "{:e} + {:e}i", self.re, self.im)
+		}
+	}
+}
+
+//reads "a + bi" as written by Display, as well as "a - bi", "a", "bi" and the same without spaces or in brackets
+//a b of 1 or -1 can be left out, as in "i", "-i" or "a - i"
+impl<T: FromStr + Zero> FromStr for Complex<T> {
+	type Err = <T as FromStr>::Err;
+	
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+		let s = s.trim_matches(BRACKETS);
+		let coefficient = |im: &str| match im {
+			"" | "+" => "1".parse(),
+			"-" => "-1".parse(),
+			_ => im.parse(),
+		};
+		let body = match s.strip_suffix('i') {
+			Some(body) => body,
+			None => return Ok(complex(s.parse()?, T::zero())),
+		};
+		//the sign between the parts: not a leading sign, the sign of an exponent or the sign of the imaginary part itself
+		let split = body.char_indices().rev()
+			.find(|&(i, c)| (c == '+' || c == '-') && i > 0 && !body[..i].ends_with(['e', 'E', '+', '-']))
+			.map(|(i, _)| i);
+		match split {
+			Some(i) => {
+				let im = &body[i..];
+				let im = im.strip_prefix('+').unwrap_or(im);
+				let im = im.strip_prefix("--").unwrap_or(im);
+				Ok(complex(body[..i].parse()?, coefficient(im)?))
+			},
+			None => Ok(complex(T::zero(), coefficient(body)?)),
+		}
+	}
+}
+
+//a rotation stored as the unit complex number (cos, sin), rotating is multiplying by it
+//composing these and renormalising now and then drifts less than composing rotation matrices
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Rotation2<T> {
+	pub complex: Complex<T>,
+}
+
+impl<T> Rotation2<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		Rotation2{ complex: Complex::one() }
+	}
+	
+	//counter clockwise, like Mat2::rotate
+	pub fn from_angle(angle: impl Into<Rad<T>>) -> Self
+		where T: Copy + Trig {
+		let Rad(angle) = angle.into();
+		let (s, c) = angle.sin_cos();
+		Rotation2{ complex: complex(c, s) }
+	}
+	
+	//scales c to unit length
+	pub fn from_complex(c: Complex<T>) -> Self
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		Rotation2{ complex: c / c.abs() }
+	}
+	
+	//the rotation taking the direction of from to the direction of to
+	pub fn from_rotation_arc(from: Vec2<T>, to: Vec2<T>) -> Self
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		Self::from_complex(complex(dot(from, to), perp_dot(from, to)))
+	}
+	
+	//in (-pi, pi]
+	pub fn angle(self) -> Rad<T>
+		where T: Copy + Trig {
+		Rad(self.complex.arg())
+	}
+	
+	//removes the drift from a long chain of products
+	pub fn normalise(self) -> Self
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		Self::from_complex(self.complex)
+	}
+	
+	pub fn inverse(self) -> Self
+		where T: Neg<Output=T> {
+		Rotation2{ complex: self.complex.conj() }
+	}
+	
+	pub fn rotate(self, v: Vec2<T>) -> Vec2<T>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		let Complex{ re: c, im: s } = self.complex;
+		vec2(c * v.x - s * v.y, s * v.x + c * v.y)
+	}
+	
+	//constant angular speed along the shorter way round
+	pub fn slerp(self, other: Self, t: T) -> Self
+		where T: Copy + Trig + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		let delta = Rad((self.complex.conj() * other.complex).arg());
+		self * Self::from_angle(delta * t)
+	}
+	
+	pub fn to_mat2(self) -> Mat2<T>
+		where T: Copy + Neg<Output=T> {
+		let Complex{ re: c, im: s } = self.complex;
+		mat2(vec2(c, -s), vec2(s, c))
+	}
+}
+
+impl<T> Default for Rotation2<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+//self after other
+impl<T> Mul<Rotation2<T>> for Rotation2<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		Rotation2{ complex: self.complex * other.complex }
+	}
+}
+
+impl<T> Mul<Vec2<T>> for Rotation2<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Vec2<T>;
+	
+	fn mul(self, v: Vec2<T>) -> Vec2<T> {
+		self.rotate(v)
+	}
+}
@@ -0,0 +1,367 @@
+use crate::prelude::*;
+
+use crate::vecn::*;
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::matn::*;
+use crate::mat2::*;
+use crate::mat3::*;
+use crate::mat4::*;
+
+use std::array;
+
+//P A = L U, L (unit diagonal, not stored) and U share one matrix
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Lu<T, const N: usize> {
+	pub lu: Mat<T, N, N>,
+	//row i of P A is row perm[i] of A
+	pub perm: [usize; N],
+	//true if perm is an odd number of swaps
+	pub odd: bool,
+}
+
+//A = Q R, Q orthogonal and R upper triangular
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Qr<T, const N: usize> {
+	pub q: Mat<T, N, N>,
+	pub r: Mat<T, N, N>,
+}
+
+//A = L L^T, L lower triangular with a positive diagonal
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Cholesky<T, const N: usize> {
+	pub l: Mat<T, N, N>,
+}
+
+impl<T, const N: usize> Mat<T, N, N> {
+	//Doolittle with partial pivoting, None if the matrix is singular
+	pub fn lu(self) -> Option<Lu<T, N>>
+		where T: Copy + PartialOrd + Zero + Neg<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let mut a = self;
+		let mut perm: [usize; N] = array::from_fn(|i| i);
+		let mut odd = false;
+		for k in 0..N {
+			let p = (k..N).fold(k, |p, i| if abs(a[i][k]) > abs(a[p][k]) { i } else { p });
+			if a[p][k] == T::zero() || a[p][k].is_nan() { return None; }
+			if p != k {
+				a.0.swap(p, k);
+				perm.swap(p, k);
+				odd = !odd;
+			}
+			for i in k + 1..N {
+				let f = a[i][k] / a[k][k];
+				a[i][k] = f;
+				for j in k + 1..N {
+					a[i][j] = a[i][j] - f * a[k][j];
+				}
+			}
+		}
+		Some(Lu{ lu: a, perm, odd })
+	}
+	
+	//Householder reflections, always succeeds (R has a zero on its diagonal if the matrix is singular)
+	pub fn qr(self) -> Qr<T, N>
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let mut r = self;
+		let mut q = Mat::ident();
+		for k in 0..N.saturating_sub(1) {
+			let norm = (k..N).fold(T::zero(), |s, i| s + r[i][k] * r[i][k]).sqrt();
+			if norm == T::zero() { continue; }
+			//reflect the column onto -sign(r[k][k]) * norm * e_k, the sign avoids cancellation
+			let alpha = if r[k][k] > T::zero() { -norm } else { norm };
+			let mut v: [T; N] = array::from_fn(|i| if i < k { T::zero() } else { r[i][k] });
+			v[k] = v[k] - alpha;
+			let v_norm_sq = v.iter().fold(T::zero(), |s, &a| s + a * a);
+			if v_norm_sq == T::zero() { continue; }
+			//H = I - 2 v v^T / (v^T v), applied as R = H R and Q = Q H
+			for j in 0..N {
+				let d = (k..N).fold(T::zero(), |s, i| s + v[i] * r[i][j]) * T::two() / v_norm_sq;
+				for i in k..N {
+					r[i][j] = r[i][j] - d * v[i];
+				}
+			}
+			for i in 0..N {
+				let d = (k..N).fold(T::zero(), |s, j| s + q[i][j] * v[j]) * T::two() / v_norm_sq;
+				for j in k..N {
+					q[i][j] = q[i][j] - d * v[j];
+				}
+			}
+		}
+		Qr{ q, r }
+	}
+	
+	//only the lower triangle is read, the matrix is assumed to be symmetric
+	//None if it isn't positive definite
+	pub fn cholesky(self) -> Option<Cholesky<T, N>>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let mut l = Mat::zero();
+		for j in 0..N {
+			let d = (0..j).fold(self[j][j], |s, k| s - l[j][k] * l[j][k]);
+			if d <= T::zero() || d.is_nan() { return None; }
+			l[j][j] = d.sqrt();
+			for i in j + 1..N {
+				l[i][j] = (0..j).fold(self[i][j], |s, k| s - l[i][k] * l[j][k]) / l[j][j];
+			}
+		}
+		Some(Cholesky{ l })
+	}
+}
+
+//x from L x = b, L lower triangular, its diagonal is taken as ones if unit is set
+fn forward<T, const N: usize>(l: &Mat<T, N, N>, b: VecN<T, N>, unit: bool) -> VecN<T, N>
+	where T: Copy + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+	let mut x = b;
+	for i in 0..N {
+		x[i] = (0..i).fold(x[i], |s, j| s - l[i][j] * x[j]);
+		if !unit { x[i] = x[i] / l[i][i]; }
+	}
+	x
+}
+
+//x from U x = b, U upper triangular
+fn back<T, const N: usize>(u: &Mat<T, N, N>, b: VecN<T, N>) -> VecN<T, N>
+	where T: Copy + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+	let mut x = b;
+	for i in (0..N).rev() {
+		x[i] = (i + 1..N).fold(x[i], |s, j| s - u[i][j] * x[j]) / u[i][i];
+	}
+	x
+}
+
+impl<T, const N: usize> Lu<T, N> {
+	pub fn solve(&self, b: VecN<T, N>) -> VecN<T, N>
+		where T: Copy + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let pb = VecN(self.perm.map(|i| b[i]));
+		back(&self.lu, forward(&self.lu, pb, true))
+	}
+	
+	pub fn det(&self) -> T
+		where T: Copy + One + Neg<Output=T> + Mul<Output=T> {
+		let d = (0..N).fold(T::one(), |d, i| d * self.lu[i][i]);
+		if self.odd { -d } else { d }
+	}
+	
+	pub fn inverse(&self) -> Mat<T, N, N>
+		where T: Copy + Zero + One + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let ident = Mat::<T, N, N>::ident();
+		//solving for each column of the identity gives the columns of the inverse
+		Mat(array::from_fn(|c| self.solve(ident.row(c)))).transpose()
+	}
+	
+	pub fn l(&self) -> Mat<T, N, N>
+		where T: Copy + Zero + One {
+		Mat(array::from_fn(|i| VecN(array::from_fn(|j| if i == j { T::one() } else if j < i { self.lu[i][j] } else { T::zero() }))))
+	}
+	
+	pub fn u(&self) -> Mat<T, N, N>
+		where T: Copy + Zero {
+		Mat(array::from_fn(|i| VecN(array::from_fn(|j| if j >= i { self.lu[i][j] } else { T::zero() }))))
+	}
+	
+	//the permutation matrix P
+	pub fn p(&self) -> Mat<T, N, N>
+		where T: Zero + One {
+		Mat(array::from_fn(|i| VecN(array::from_fn(|j| if self.perm[i] == j { T::one() } else { T::zero() }))))
+	}
+}
+
+impl<T, const N: usize> Qr<T, N> {
+	//least squares isn't supported, None if R is singular
+	pub fn solve(&self, b: VecN<T, N>) -> Option<VecN<T, N>>
+		where T: Copy + PartialEq + Zero + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		if (0..N).any(|i| self.r[i][i] == T::zero()) { return None; }
+		//Q^-1 = Q^T
+		Some(back(&self.r, self.q.transpose().apply_to(b)))
+	}
+}
+
+impl<T, const N: usize> Cholesky<T, N> {
+	pub fn solve(&self, b: VecN<T, N>) -> VecN<T, N>
+		where T: Copy + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		back(&self.l.transpose(), forward(&self.l, b, false))
+	}
+}
+
+impl<T> Mat2<T> {
+	pub fn lu(self) -> Option<Lu<T, 2>>
+		where T: Copy + PartialOrd + Zero + Neg<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).lu()
+	}
+	
+	pub fn qr(self) -> Qr<T, 2>
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).qr()
+	}
+	
+	pub fn cholesky(self) -> Option<Cholesky<T, 2>>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).cholesky()
+	}
+}
+
+impl<T> Mat3<T> {
+	pub fn lu(self) -> Option<Lu<T, 3>>
+		where T: Copy + PartialOrd + Zero + Neg<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).lu()
+	}
+	
+	pub fn qr(self) -> Qr<T, 3>
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).qr()
+	}
+	
+	pub fn cholesky(self) -> Option<Cholesky<T, 3>>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).cholesky()
+	}
+}
+
+impl<T> Mat4<T> {
+	pub fn lu(self) -> Option<Lu<T, 4>>
+		where T: Copy + PartialOrd + Zero + Neg<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).lu()
+	}
+	
+	pub fn qr(self) -> Qr<T, 4>
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).qr()
+	}
+	
+	pub fn cholesky(self) -> Option<Cholesky<T, 4>>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Mat::from(self).cholesky()
+	}
+}
+
+//eigenvalues come largest first, with the matching unit eigenvectors as the columns of the matrix
+//the eigenvector matrix is a rotation (determinant 1)
+impl<T> Mat2<T> {
+	//closed form, only the upper triangle is read
+	pub fn symmetric_eigen(self) -> (Vec2<T>, Mat2<T>)
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (a, b, d) = (self.x.x, self.x.y, self.y.y);
+		let (mean, half_diff) = ((a + d) / T::two(), (a - d) / T::two());
+		let r = (half_diff * half_diff + b * b).sqrt();
+		let (s, c) = ((b * T::two()).atan2(a - d) / T::two()).sin_cos();
+		(vec2(mean + r, mean - r), mat2(vec2(c, -s), vec2(s, c)))
+	}
+	
+	//self = u * diagonal(sigma) * v^T, sigma is largest first and not negative, u and v are orthogonal
+	pub fn svd(self) -> (Mat2<T>, Vec2<T>, Mat2<T>)
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		//the right singular vectors are the eigenvectors of A^T A
+		let (_, v) = (self.transpose() * self).symmetric_eigen();
+		let vt = v.transpose();
+		let (v1, v2) = (vt.x, vt.y);
+		let av1 = self.apply_to(v1);
+		let sigma1 = av1.magnitude();
+		let u1 = if sigma1 == T::zero() { vec2(T::one(), T::zero()) } else { av1 / sigma1 };
+		let mut u2 = u1.perp();
+		let mut sigma2 = dot(self.apply_to(v2), u2);
+		if sigma2 < T::zero() {
+			sigma2 = -sigma2;
+			u2 = -u2;
+		}
+		(mat2(u1, u2).transpose(), vec2(sigma1, sigma2), v)
+	}
+	
+	//self = rotation * stretch, stretch symmetric, it has a negative eigenvalue if self flips orientation
+	pub fn polar(self) -> (Mat2<T>, Mat2<T>)
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (mut u, mut sigma, v) = self.svd();
+		if u.det() * v.det() < T::zero() {
+			u.x.y = -u.x.y;
+			u.y.y = -u.y.y;
+			sigma.y = -sigma.y;
+		}
+		let stretch = v * Mat2::from([[sigma.x, T::zero()], [T::zero(), sigma.y]]) * v.transpose();
+		(u * v.transpose(), stretch)
+	}
+}
+
+impl<T> Mat3<T> {
+	//cyclic Jacobi rotations until the off diagonal underflows next to the diagonal, only the upper triangle is read
+	pub fn symmetric_eigen(self) -> (Vec3<T>, Mat3<T>)
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let mut a = self.into_array();
+		a[1][0] = a[0][1];
+		a[2][0] = a[0][2];
+		a[2][1] = a[1][2];
+		let mut v = Mat3::ident();
+		for _ in 0..32 {
+			let mut done = true;
+			for &(p, q) in &[(0, 1), (0, 2), (1, 2)] {
+				let apq = a[p][q];
+				if apq == T::zero() { continue; }
+				//too small to change either diagonal element, so it's as good as zero
+				if abs(a[p][p]) + abs(apq) == abs(a[p][p]) && abs(a[q][q]) + abs(apq) == abs(a[q][q]) {
+					a[p][q] = T::zero();
+					a[q][p] = T::zero();
+					continue;
+				}
+				done = false;
+				//the rotation in the p q plane that zeroes a[p][q] (numerical recipes 11.1)
+				let theta = (a[q][q] - a[p][p]) / (T::two() * apq);
+				let t = T::one() / (abs(theta) + (theta * theta + T::one()).sqrt());
+				let t = if theta < T::zero() { -t } else { t };
+				let c = T::one() / (t * t + T::one()).sqrt();
+				let s = t * c;
+				let mut j = Mat3::<T>::ident().into_array();
+				j[p][p] = c;
+				j[q][q] = c;
+				j[p][q] = s;
+				j[q][p] = -s;
+				let j = Mat3::from(j);
+				a = (j.transpose() * Mat3::from(a) * j).into_array();
+				v *= j;
+			}
+			if done { break; }
+		}
+		let vt = v.transpose();
+		let mut pairs = [(a[0][0], vt.x), (a[1][1], vt.y), (a[2][2], vt.z)];
+		pairs.sort_by(|x, y| y.0.partial_cmp(&x.0).unwrap_or(std::cmp::Ordering::Equal));
+		let [(l0, c0), (l1, c1), (l2, _)] = pairs;
+		(vec3(l0, l1, l2), mat3(c0, c1, cross(c0, c1)).transpose())
+	}
+	
+	//self = u * diagonal(sigma) * v^T, sigma is largest first and not negative, u and v are orthogonal
+	pub fn svd(self) -> (Mat3<T>, Vec3<T>, Mat3<T>)
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		//the right singular vectors are the eigenvectors of A^T A, the left ones are A v / sigma
+		//made orthonormal one by one so rank deficient matrices still give an orthogonal u
+		let (_, v) = (self.transpose() * self).symmetric_eigen();
+		let vt = v.transpose();
+		let (v1, v2, v3) = (vt.x, vt.y, vt.z);
+		let av1 = self.apply_to(v1);
+		let sigma1 = av1.magnitude();
+		let u1 = if sigma1 == T::zero() { vec3(T::one(), T::zero(), T::zero()) } else { av1 / sigma1 };
+		let av2 = self.apply_to(v2);
+		let w = av2 - u1 * dot(av2, u1);
+		let len = w.magnitude();
+		let u2 = if len == T::zero() { any_perpendicular(u1) } else { w / len };
+		let sigma2 = dot(av2, u2);
+		let mut u3 = cross(u1, u2);
+		let mut sigma3 = dot(self.apply_to(v3), u3);
+		if sigma3 < T::zero() {
+			sigma3 = -sigma3;
+			u3 = -u3;
+		}
+		(mat3(u1, u2, u3).transpose(), vec3(sigma1, sigma2, sigma3), v)
+	}
+	
+	//self = rotation * stretch, stretch symmetric, it has a negative eigenvalue if self flips orientation
+	//this is the rotation shape matching wants: the closest one to self
+	pub fn polar(self) -> (Mat3<T>, Mat3<T>)
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (mut u, mut sigma, v) = self.svd();
+		if u.det() * v.det() < T::zero() {
+			u.x.z = -u.x.z;
+			u.y.z = -u.y.z;
+			u.z.z = -u.z.z;
+			sigma.z = -sigma.z;
+		}
+		let zero = T::zero();
+		let stretch = v * Mat3::from([[sigma.x, zero, zero], [zero, sigma.y, zero], [zero, zero, sigma.z]]) * v.transpose();
+		(u * v.transpose(), stretch)
+	}
+}
@@ -0,0 +1,188 @@
+use crate::prelude::*;
+
+use crate::vec3::*;
+use crate::mat3::*;
+use crate::mat4::*;
+use crate::quaternion::*;
+
+//real + dual ε with ε² = 0, a unit one is a rigid transform: real is the rotation
+//and dual is half the translation times the rotation
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct DualQuaternion<T> {
+	pub real: Quaternion<T>,
+	pub dual: Quaternion<T>,
+}
+
+pub fn dual_quaternion<T>(real: Quaternion<T>, dual: Quaternion<T>) -> DualQuaternion<T> {
+	DualQuaternion{ real, dual }
+}
+
+impl<T> DualQuaternion<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		dual_quaternion(Quaternion::ident(), quaternion(T::zero(), T::zero(), T::zero(), T::zero()))
+	}
+	
+	//rotates first and then translates, rotation should be a unit quaternion
+	pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vec3<T>) -> Self
+		where T: Copy + Zero + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let t = quaternion(T::zero(), translation.x, translation.y, translation.z);
+		dual_quaternion(rotation, t * rotation / T::two())
+	}
+	
+	pub fn from_translation(translation: Vec3<T>) -> Self
+		where T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		Self::from_rotation_translation(Quaternion::ident(), translation)
+	}
+	
+	pub fn rotation(self) -> Quaternion<T> {
+		self.real
+	}
+	
+	//assumes a unit dual quaternion
+	pub fn translation(self) -> Vec3<T>
+		where T: Copy + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		(self.dual * self.real.conjugate()).vector() * T::two()
+	}
+	
+	//conjugates both parts, for a unit dual quaternion this is the inverse
+	pub fn conjugate(self) -> Self
+		where T: Neg<Output=T> {
+		dual_quaternion(self.real.conjugate(), self.dual.conjugate())
+	}
+	
+	//negates the dual part
+	pub fn dual_conjugate(self) -> Self
+		where T: Neg<Output=T> {
+		dual_quaternion(self.real, -self.dual)
+	}
+	
+	//both conjugates at once, the one that maps points as q p q*
+	pub fn combined_conjugate(self) -> Self
+		where T: Neg<Output=T> {
+		dual_quaternion(self.real.conjugate(), -self.dual.conjugate())
+	}
+	
+	//divides by the norm of the real part and drops the part of dual not orthogonal to real
+	//so the result is a rigid transform again, after blending or a long chain of products
+	pub fn normalise(self) -> Self
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let n = self.real.norm();
+		let (real, dual) = (self.real / n, self.dual / n);
+		dual_quaternion(real, dual - real * real.dot(dual))
+	}
+	
+	//assumes a unit dual quaternion
+	pub fn transform_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.real.rotate(p) + self.translation()
+	}
+	
+	//translation is ignored
+	pub fn transform_vector(self, v: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.real.rotate(v)
+	}
+	
+	//screw linear interpolation: moves along the single screw motion (a rotation about an axis
+	//combined with a translation along it) from self to other, at constant speed and along the shorter arc
+	pub fn sclerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let other = if self.real.dot(other.real) < T::zero() { -other } else { other };
+		self * (self.conjugate() * other).screw_pow(t)
+	}
+	
+	//raises a unit dual quaternion to the power t by scaling its screw angle and pitch
+	fn screw_pow(self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let v = self.real.vector();
+		let s = v.magnitude();
+		if s == T::zero() {
+			//a pure translation, which scales linearly
+			return dual_quaternion(self.real, self.dual * t);
+		}
+		let half_angle = s.atan2(self.real.r);
+		let axis = v / s;
+		let half_pitch = -self.dual.r / s;
+		let moment = (self.dual.vector() - axis * (half_pitch * self.real.r)) / s;
+		let (half_angle, half_pitch) = (half_angle * t, half_pitch * t);
+		let (sin, cos) = half_angle.sin_cos();
+		let real = quaternion(cos, axis.x * sin, axis.y * sin, axis.z * sin);
+		let d = moment * sin + axis * (half_pitch * cos);
+		dual_quaternion(real, quaternion(-half_pitch * sin, d.x, d.y, d.z))
+	}
+	
+	//dual quaternion linear blending, as used for skinning: the weighted sum normalised
+	//each one is flipped into the same hemisphere as the first so the blend takes the shorter way
+	//None when there is nothing to blend or the weights cancel out
+	pub fn blend(transforms: &[(Self, T)]) -> Option<Self>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let &(first, _) = transforms.first()?;
+		let zero = quaternion(T::zero(), T::zero(), T::zero(), T::zero());
+		let (real, dual) = transforms.iter().fold((zero, zero), |(real, dual), &(q, w)| {
+			let w = if first.real.dot(q.real) < T::zero() { -w } else { w };
+			(real + q.real * w, dual + q.dual * w)
+		});
+		if real.norm_squared() <= T::zero() { return None; }
+		Some(dual_quaternion(real, dual).normalise())
+	}
+	
+	//assumes a unit dual quaternion
+	pub fn to_mat4(self) -> Mat4<T>
+		where T: Copy + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.real.rot_mat().extend(self.translation(), Vec3::zero(), T::one())
+	}
+	
+	//assumes a rigid transform, any scale or shear is not removed first
+	pub fn from_mat4(m: Mat4<T>) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Mat4{ x,y,z,.. } = m;
+		let rotation = Quaternion::from_rot_mat(mat3(x.downsize(), y.downsize(), z.downsize()));
+		Self::from_rotation_translation(rotation, vec3(x.w, y.w, z.w))
+	}
+}
+
+impl<T> Default for DualQuaternion<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+//self after other, like the product of their matrices
+impl<T> Mul<DualQuaternion<T>> for DualQuaternion<T>
+	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		dual_quaternion(self.real * other.real, self.real * other.dual + self.dual * other.real)
+	}
+}
+
+impl<T> Mul<T> for DualQuaternion<T>
+	where T: Copy + Mul<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, scalar: T) -> Self {
+		dual_quaternion(self.real * scalar, self.dual * scalar)
+	}
+}
+
+impl<T> Add<DualQuaternion<T>> for DualQuaternion<T>
+	where T: Add<Output=T>
+{
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		dual_quaternion(self.real + other.real, self.dual + other.dual)
+	}
+}
+
+impl<T: Neg> Neg for DualQuaternion<T> {
+	type Output = DualQuaternion<<T as Neg>::Output>;
+	fn neg(self) -> DualQuaternion<<T as Neg>::Output> { dual_quaternion(-self.real, -self.dual) }
+}
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+use crate::mat2::*;
+use crate::mat3::*;
+use crate::mat4::*;
+use crate::quaternion::*;
+use crate::vecn::*;
+use crate::matn::*;
+
+//all of these are repr(C) structs of nothing but T, so there is never any padding between the fields
+unsafe impl<T: Pod> Pod for Vec2<T> {}
+unsafe impl<T: Pod> Pod for Vec3<T> {}
+unsafe impl<T: Pod> Pod for Vec4<T> {}
+unsafe impl<T: Pod> Pod for Mat2<T> {}
+unsafe impl<T: Pod> Pod for Mat3<T> {}
+unsafe impl<T: Pod> Pod for Mat4<T> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
+unsafe impl<T: Pod, const N: usize> Pod for VecN<T, N> {}
+unsafe impl<T: Pod, const R: usize, const C: usize> Pod for Mat<T, R, C> {}
+
+//a vec3 as std140 and std430 lay it out: 16 byte aligned with a trailing padding element
+//meant for 4 byte scalars, which is what glsl's vec3/ivec3/uvec3 are made of
+#[repr(C, align(16))]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Std140Vec3<T> {
+	pub x: T,
+	pub y: T,
+	pub z: T,
+	pub pad: T,
+}
+
+//a mat3 as std140 and std430 lay it out: three columns, each padded out to a vec4
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Std140Mat3<T> {
+	pub x: Std140Vec3<T>,
+	pub y: Std140Vec3<T>,
+	pub z: Std140Vec3<T>,
+}
+
+//std430 only differs from std140 for arrays of scalars/vec2 and for mat2, so vec3 and mat3 look the same in both
+pub type Std430Vec3<T> = Std140Vec3<T>;
+pub type Std430Mat3<T> = Std140Mat3<T>;
+
+//only 4 byte scalars fill the 16 byte alignment exactly, anything smaller would leave padding bytes behind
+unsafe impl Pod for Std140Vec3<f32> {}
+unsafe impl Pod for Std140Vec3<i32> {}
+unsafe impl Pod for Std140Vec3<u32> {}
+unsafe impl Pod for Std140Mat3<f32> {}
+unsafe impl Pod for Std140Mat3<i32> {}
+unsafe impl Pod for Std140Mat3<u32> {}
+
+impl<T: Zero> From<Vec3<T>> for Std140Vec3<T> {
+	fn from(v: Vec3<T>) -> Self {
+		let Vec3{ x,y,z } = v;
+		Std140Vec3{ x, y, z, pad: T::zero() }
+	}
+}
+
+impl<T> From<Std140Vec3<T>> for Vec3<T> {
+	fn from(v: Std140Vec3<T>) -> Self {
+		vec3(v.x, v.y, v.z)
+	}
+}
+
+//the columns of the matrix become the padded vectors
+impl<T: Copy + Zero> From<Mat3<T>> for Std140Mat3<T> {
+	fn from(m: Mat3<T>) -> Self {
+		let Mat3{ x,y,z } = m.transpose();
+		Std140Mat3{ x: x.into(), y: y.into(), z: z.into() }
+	}
+}
+
+impl<T: Copy> From<Std140Mat3<T>> for Mat3<T> {
+	fn from(m: Std140Mat3<T>) -> Self {
+		mat3(m.x.into(), m.y.into(), m.z.into()).transpose()
+	}
+}
@@ -11,6 +11,21 @@ pub mod mat2;
 pub mod mat3;
 pub mod mat4;
 pub mod quaternion;
+pub mod vecn;
+pub mod matn;
+pub mod layout;
+pub mod aabb;
+pub mod shapes;
+pub mod ray;
+pub mod overlap;
+pub mod polygon;
+pub mod decomposition;
+pub mod transform;
+pub mod dual_quaternion;
+pub mod complex;
+pub mod rotor;
+pub mod angle;
 pub mod traits;
 
 mod prelude;
+mod swizzle;
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 use crate::vec2::*;
+use crate::angle::*;
 
 #[repr(C)]
 #[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
@@ -16,15 +17,50 @@ impl<T> Mat2<T> {
 		x.x * y.y - x.y * y.x
 	}
 	
-	pub fn inv(self) -> Self
-	where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
+	pub fn cofactor(self) -> Self
+		where T: Copy + Neg<Output=T> {
 		let Self{ x,y } = self;
 		mat2(
-			vec2(y.y, -y.x) / self.det(),
-			vec2(-x.y, x.x) / self.det(),
+			vec2(y.y, -y.x),
+			vec2(-x.y, x.x),
 		)
 	}
 	
+	pub fn adjoint(self) -> Self
+		where T: Copy + Neg<Output=T> {
+		self.cofactor().transpose()
+	}
+	
+	pub fn inv(self) -> Self
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
+		self.inv_with_det().0
+	}
+	
+	pub fn inv_with_det(self) -> (Self, T)
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
+		let det = self.det();
+		let Mat2{ x,y } = self.adjoint();
+		(mat2(x/det,y/det), det)
+	}
+	
+	//returns None when the determinant is exactly zero
+	pub fn try_inv(self) -> Option<Self>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> + PartialEq + Zero {
+		let det = self.det();
+		if det == T::zero() { return None; }
+		let Mat2{ x,y } = self.adjoint();
+		Some(mat2(x/det,y/det))
+	}
+	
+	//returns None when |det| <= epsilon, or when the determinant is NaN
+	pub fn try_inv_eps(self, epsilon: T) -> Option<Self>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> + PartialOrd {
+		let det = self.det();
+		if !(det > epsilon || det < -epsilon) { return None; }
+		let Mat2{ x,y } = self.adjoint();
+		Some(mat2(x/det,y/det))
+	}
+	
 	pub fn ident() -> Self
 		where T: Zero + One {
 		mat2(
@@ -33,6 +69,11 @@ impl<T> Mat2<T> {
 		)
 	}
 	
+	pub fn zero() -> Self
+		where T: Zero {
+		mat2(Vec2::zero(), Vec2::zero())
+	}
+	
 	pub fn apply_to(self, v: Vec2<T>) -> Vec2<T>
 		where Vec2<T>: Vector<T> + Copy {
 		vec2(
@@ -41,6 +82,20 @@ impl<T> Mat2<T> {
 		)
 	}
 	
+	//flattened column by column, the order glsl/wgsl/spir-v expect for a matrix
+	pub fn to_cols_array(self) -> [T; 4] {
+		let Mat2{ x,y } = self;
+		[x.x, y.x, x.y, y.y]
+	}
+	
+	pub fn from_cols_array(a: [T; 4]) -> Self {
+		let [a, b, c, d] = a;
+		mat2(
+			vec2(a, c),
+			vec2(b, d),
+		)
+	}
+	
 	pub fn transpose(self) -> Self {
 		mat2(
 			vec2(self.x.x, self.y.x),
@@ -48,8 +103,9 @@ impl<T> Mat2<T> {
 		)
 	}
 	
-	pub fn rotate(angle: T) -> Self
+	pub fn rotate(angle: impl Into<Rad<T>>) -> Self
 		where T: Copy + Trig + Neg<Output=T> {
+		let Rad(angle) = angle.into();
 		mat2(
 			vec2(angle.cos(), -angle.sin()),
 			vec2(angle.sin(), angle.cos()),
@@ -61,71 +117,79 @@ pub fn mat2<T>(x: Vec2<T>, y: Vec2<T>) -> Mat2<T> {
 	Mat2 { x: x, y: y }
 }
 
-impl<T> Default for Mat2<T>
-	where T: Zero + One
-{
-	fn default() -> Self {
-		Mat2::ident()
+impl<T> Mul<Mat2<T>> for Mat2<T>
+	where Vec2<T>: Vector<T> + Copy {
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		let t = other.transpose();
+		mat2(
+			vec2(dot(self.x,t.x), dot(self.x,t.y)),
+			vec2(dot(self.y,t.x), dot(self.y,t.y)),
+		)
 	}
 }
 
-impl<T> Add<Self> for Mat2<T>
-	where T: Add<Output=T> {
-	type Output = Self;
+impl<T> Mul<Vec2<T>> for Mat2<T>
+	where Vec2<T>: Vector<T> + Copy {
+	type Output = Vec2<T>;
 	
-	fn add(self, other: Self) -> Self {
-		mat2(self.x + other.x, self.y + other.y)
+	fn mul(self, v: Vec2<T>) -> Vec2<T> {
+		self.apply_to(v)
 	}
 }
 
-impl<T> AddAssign<Self> for Mat2<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, other: Self) {
-		*self = *self + other;
+impl<T> MulAssign<Mat2<T>> for Mat2<T>
+	where T: Copy, Vec2<T>: Vector<T> + Copy {
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
 	}
 }
 
-impl<T> Sub<Self> for Mat2<T>
-	where T: Sub<Output=T> {
-	type Output = Self;
+//row vector times matrix
+impl<T> Mul<Mat2<T>> for Vec2<T>
+	where Vec2<T>: Vector<T> + Copy {
+	type Output = Vec2<T>;
 	
-	fn sub(self, other: Self) -> Self {
-		mat2(self.x - other.x, self.y - other.y)
+	fn mul(self, m: Mat2<T>) -> Vec2<T> {
+		m.transpose().apply_to(self)
 	}
 }
 
-impl<T> SubAssign<Self> for Mat2<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, other: Self) {
-		*self = *self - other;
+impl<T> MulAssign<Mat2<T>> for Vec2<T>
+	where Vec2<T>: Vector<T> + Copy {
+	fn mul_assign(&mut self, m: Mat2<T>) {
+		*self = *self * m;
 	}
 }
 
-impl<T> Mul<Mat2<T>> for Mat2<T>
+impl<T: Zero + One> Product<Mat2<T>> for Mat2<T>
 	where Vec2<T>: Vector<T> + Copy {
-	type Output = Self;
-	
-	fn mul(self, other: Self) -> Self {
-		let t = other.transpose();
-		mat2(
-			vec2(dot(self.x,t.x), dot(self.x,t.y)),
-			vec2(dot(self.y,t.x), dot(self.y,t.y)),
-		)
+	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::ident(), |a, b| a * b)
 	}
 }
 
-impl<T> Mul<Vec2<T>> for Mat2<T>
-	where Vec2<T>: Vector<T> + Copy {
+impl<T> Index<usize> for Mat2<T> {
 	type Output = Vec2<T>;
 	
-	fn mul(self, v: Vec2<T>) -> Vec2<T> {
-		self.apply_to(v)
+	fn index(&self, index: usize) -> &Vec2<T> {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			_ => panic!("index out of bounds, index is {} but the len is 2",index),
+		}
 	}
 }
 
-impl<T: Neg> Neg for Mat2<T> {
-	type Output = Mat2<<T as Neg>::Output>;
-	fn neg(self) -> Mat2<<T as Neg>::Output> { mat2(-self.x,-self.y) }
+impl<T> IndexMut<usize> for Mat2<T> {
+	fn index_mut(&mut self, index: usize) -> &mut Vec2<T> {
+		match index {
+			0 => &mut self.x,
+			1 => &mut self.y,
+			_ => panic!("index out of bounds, index is {} but the len is 2",index),
+		}
+	}
 }
 
 impl<T> ArrayTuple for Mat2<T> {
@@ -135,26 +199,17 @@ impl<T> ArrayTuple for Mat2<T> {
 	fn into_tuple(self) -> Self::Tuple { let Self{x,y} = self; (x.into_tuple(),y.into_tuple()) }
 }
 
-macro convert($T: ty, $($U: ident),*) {
-	$(
-		impl Mat2<$T> {
-			pub fn $U(self) -> Mat2<$U> {
-				mat2(self.x.$U(), self.y.$U())
-			}
-		}
-	)*
+impl<T> From<((T,T),(T,T))> for Mat2<T> {
+	fn from(t: ((T,T),(T,T))) -> Self {
+		let (x,y) = t;
+		mat2(x.into(), y.into())
+	}
+}
+
+impl<T> From<[[T; 2]; 2]> for Mat2<T> {
+	fn from(a: [[T; 2]; 2]) -> Self {
+		let [x,y] = a;
+		mat2(x.into(), y.into())
+	}
 }
 
-convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
@@ -4,6 +4,7 @@ use crate::vec2::*;
 use crate::vec3::*;
 use crate::mat2::*;
 use crate::mat4::*;
+use crate::angle::*;
 
 #[repr(C)]
 #[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
@@ -25,22 +26,27 @@ impl<T> Mat3<T> {
 		- x.z * y.y * z.x
 	}
 	
+	pub fn minor(self, row: usize, col: usize) -> Mat2<T>
+		where T: Copy {
+		let m = self.into_array();
+		let skip = |i: usize, n: usize| if n < i { n } else { n + 1 };
+		let (r, c) = ([skip(row, 0), skip(row, 1)], [skip(col, 0), skip(col, 1)]);
+		mat2(
+			vec2(m[r[0]][c[0]], m[r[0]][c[1]]),
+			vec2(m[r[1]][c[0]], m[r[1]][c[1]]),
+		)
+	}
+	
 	pub fn cofactor(self) -> Self
 		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
-		let Mat3{ x,y,z } = self;
+		let c = |row: usize, col: usize| {
+			let d = self.minor(row, col).det();
+			if (row + col) % 2 == 1 { -d } else { d }
+		};
 		mat3(
-			vec3(
-				mat2(vec2(y.y,y.z),vec2(z.y,z.z)).det(),
-				-mat2(vec2(y.x,y.z),vec2(z.x,z.z)).det(),
-				mat2(vec2(y.x,y.y),vec2(z.x,z.y)).det()),
-			vec3(
-				-mat2(vec2(x.y,x.z),vec2(z.y,z.z)).det(),
-				mat2(vec2(x.x,x.z),vec2(z.x,z.z)).det(),
-				-mat2(vec2(x.x,x.y),vec2(z.x,z.y)).det()),
-			vec3(
-				mat2(vec2(x.y,x.z),vec2(y.y,y.z)).det(),
-				-mat2(vec2(x.x,x.z),vec2(y.x,y.z)).det(),
-				mat2(vec2(x.x,x.y),vec2(y.x,y.y)).det()),
+			vec3(c(0,0), c(0,1), c(0,2)),
+			vec3(c(1,0), c(1,1), c(1,2)),
+			vec3(c(2,0), c(2,1), c(2,2)),
 		)
 	}
 	
@@ -51,8 +57,32 @@ impl<T> Mat3<T> {
 	
 	pub fn inv(self) -> Self
 		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
+		self.inv_with_det().0
+	}
+	
+	pub fn inv_with_det(self) -> (Self, T)
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
+		let det = self.det();
 		let Mat3{ x,y,z } = self.adjoint();
-		mat3(x/self.det(),y/self.det(),z/self.det())
+		(mat3(x/det,y/det,z/det), det)
+	}
+	
+	//returns None when the determinant is exactly zero
+	pub fn try_inv(self) -> Option<Self>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> + PartialEq + Zero {
+		let det = self.det();
+		if det == T::zero() { return None; }
+		let Mat3{ x,y,z } = self.adjoint();
+		Some(mat3(x/det,y/det,z/det))
+	}
+	
+	//returns None when |det| <= epsilon, or when the determinant is NaN
+	pub fn try_inv_eps(self, epsilon: T) -> Option<Self>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> + PartialOrd {
+		let det = self.det();
+		if !(det > epsilon || det < -epsilon) { return None; }
+		let Mat3{ x,y,z } = self.adjoint();
+		Some(mat3(x/det,y/det,z/det))
 	}
 	
 	pub fn ident() -> Self
@@ -64,6 +94,11 @@ impl<T> Mat3<T> {
 		)
 	}
 	
+	pub fn zero() -> Self
+		where T: Zero {
+		mat3(Vec3::zero(), Vec3::zero(), Vec3::zero())
+	}
+	
 	pub fn apply_to(self, v: Vec3<T>) -> Vec3<T>
 		where Vec3<T>: Vector<T> + Copy {
 		vec3(
@@ -82,6 +117,21 @@ impl<T> Mat3<T> {
 		)
 	}
 
+	//flattened column by column, the order glsl/wgsl/spir-v expect for a matrix
+	pub fn to_cols_array(self) -> [T; 9] {
+		let Mat3{ x,y,z } = self;
+		[x.x, y.x, z.x, x.y, y.y, z.y, x.z, y.z, z.z]
+	}
+	
+	pub fn from_cols_array(a: [T; 9]) -> Self {
+		let [a, b, c, d, e, f, g, h, i] = a;
+		mat3(
+			vec3(a, d, g),
+			vec3(b, e, h),
+			vec3(c, f, i),
+		)
+	}
+	
 	pub fn transpose(self) -> Self {
 		mat3(
 			vec3(self.x.x, self.y.x, self.z.x),
@@ -90,8 +140,9 @@ impl<T> Mat3<T> {
 		)
 	}
 	
-	pub fn rotate_x(angle: T) -> Self
+	pub fn rotate_x(angle: impl Into<Rad<T>>) -> Self
 		where T: Copy + Trig + Neg<Output=T> + Zero + One {
+		let Rad(angle) = angle.into();
 		mat3(
 			vec3(T::one(), T::zero(), T::zero()),
 			vec3(T::zero(), angle.cos(), -angle.sin()),
@@ -99,8 +150,9 @@ impl<T> Mat3<T> {
 		)
 	}
 
-	pub fn rotate_y(angle: T) -> Self
+	pub fn rotate_y(angle: impl Into<Rad<T>>) -> Self
 		where T: Copy + Trig + Neg<Output=T> + Zero + One {
+		let Rad(angle) = angle.into();
 		mat3(
 			vec3(angle.cos(), T::zero(), angle.sin()),
 			vec3(T::zero(), T::one(), T::zero()),
@@ -108,8 +160,9 @@ impl<T> Mat3<T> {
 		)
 	}
 
-	pub fn rotate_z(angle: T) -> Self
+	pub fn rotate_z(angle: impl Into<Rad<T>>) -> Self
 		where T: Copy + Trig + Neg<Output=T> + Zero + One {
+		let Rad(angle) = angle.into();
 		mat3(
 			vec3(angle.cos(), -angle.sin(), T::zero()),
 			vec3(angle.sin(),  angle.cos(), T::zero()),
@@ -122,71 +175,82 @@ pub fn mat3<T>(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Mat3<T> {
 	Mat3 { x: x, y: y, z: z }
 }
 
-impl<T> Default for Mat3<T>
-	where T: Zero + One {
-	fn default() -> Self {
-		Mat3::ident()
+impl<T> Mul<Mat3<T>> for Mat3<T>
+	where Vec3<T>: Vector<T> + Copy {
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		let t = other.transpose();
+		mat3(
+			vec3(dot(self.x,t.x), dot(self.x,t.y), dot(self.x,t.z)),
+			vec3(dot(self.y,t.x), dot(self.y,t.y), dot(self.y,t.z)),
+			vec3(dot(self.z,t.x), dot(self.z,t.y), dot(self.z,t.z)),
+		)
 	}
 }
 
-impl<T> Add<Self> for Mat3<T>
-	where T: Add<Output=T> {
-	type Output = Self;
+impl<T> Mul<Vec3<T>> for Mat3<T>
+	where Vec3<T>: Vector<T> + Copy {
+	type Output = Vec3<T>;
 	
-	fn add(self, other: Self) -> Self {
-		mat3(self.x + other.x, self.y + other.y, self.z + other.z)
+	fn mul(self, v: Vec3<T>) -> Vec3<T> {
+		self.apply_to(v)
 	}
 }
 
-impl<T> AddAssign<Self> for Mat3<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, other: Self) {
-		*self = *self + other;
+impl<T> MulAssign<Mat3<T>> for Mat3<T>
+	where T: Copy, Vec3<T>: Vector<T> + Copy {
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
 	}
 }
 
-impl<T> Sub<Self> for Mat3<T>
-	where T: Sub<Output=T> {
-	type Output = Self;
+//row vector times matrix
+impl<T> Mul<Mat3<T>> for Vec3<T>
+	where Vec3<T>: Vector<T> + Copy {
+	type Output = Vec3<T>;
 	
-	fn sub(self, other: Self) -> Self {
-		mat3(self.x - other.x, self.y - other.y, self.z - other.z)
+	fn mul(self, m: Mat3<T>) -> Vec3<T> {
+		m.transpose().apply_to(self)
 	}
 }
 
-impl<T> SubAssign<Self> for Mat3<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, other: Self) {
-		*self = *self - other;
+impl<T> MulAssign<Mat3<T>> for Vec3<T>
+	where Vec3<T>: Vector<T> + Copy {
+	fn mul_assign(&mut self, m: Mat3<T>) {
+		*self = *self * m;
 	}
 }
 
-impl<T> Mul<Mat3<T>> for Mat3<T>
+impl<T: Zero + One> Product<Mat3<T>> for Mat3<T>
 	where Vec3<T>: Vector<T> + Copy {
-	type Output = Self;
-	
-	fn mul(self, other: Self) -> Self {
-		let t = other.transpose();
-		mat3(
-			vec3(dot(self.x,t.x), dot(self.x,t.y), dot(self.x,t.z)),
-			vec3(dot(self.y,t.x), dot(self.y,t.y), dot(self.y,t.z)),
-			vec3(dot(self.z,t.x), dot(self.z,t.y), dot(self.z,t.z)),
-		)
+	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::ident(), |a, b| a * b)
 	}
 }
 
-impl<T> Mul<Vec3<T>> for Mat3<T>
-	where Vec3<T>: Vector<T> + Copy {
+impl<T> Index<usize> for Mat3<T> {
 	type Output = Vec3<T>;
 	
-	fn mul(self, v: Vec3<T>) -> Vec3<T> {
-		self.apply_to(v)
+	fn index(&self, index: usize) -> &Vec3<T> {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			2 => &self.z,
+			_ => panic!("index out of bounds, index is {} but the len is 3",index),
+		}
 	}
 }
 
-impl<T: Neg> Neg for Mat3<T> {
-	type Output = Mat3<<T as Neg>::Output>;
-	fn neg(self) -> Mat3<<T as Neg>::Output> { mat3(-self.x,-self.y,-self.z) }
+impl<T> IndexMut<usize> for Mat3<T> {
+	fn index_mut(&mut self, index: usize) -> &mut Vec3<T> {
+		match index {
+			0 => &mut self.x,
+			1 => &mut self.y,
+			2 => &mut self.z,
+			_ => panic!("index out of bounds, index is {} but the len is 3",index),
+		}
+	}
 }
 
 impl<T> ArrayTuple for Mat3<T> {
@@ -196,26 +260,17 @@ impl<T> ArrayTuple for Mat3<T> {
 	fn into_tuple(self) -> Self::Tuple { let Mat3{x,y,z} = self; (x.into_tuple(),y.into_tuple(),z.into_tuple()) }
 }
 
-macro convert($T: ty, $($U: ident),*) {
-	$(
-		impl Mat3<$T> {
-			pub fn $U(self) -> Mat3<$U> {
-				mat3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-	)*
+impl<T> From<((T,T,T),(T,T,T),(T,T,T))> for Mat3<T> {
+	fn from(t: ((T,T,T),(T,T,T),(T,T,T))) -> Self {
+		let (x,y,z) = t;
+		mat3(x.into(), y.into(), z.into())
+	}
+}
+
+impl<T> From<[[T; 3]; 3]> for Mat3<T> {
+	fn from(a: [[T; 3]; 3]) -> Self {
+		let [x,y,z] = a;
+		mat3(x.into(), y.into(), z.into())
+	}
 }
 
-convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
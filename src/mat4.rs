@@ -1,6 +1,9 @@
 use crate::prelude::*;
 
+use crate::vec3::*;
 use crate::vec4::*;
+use crate::mat3::*;
+use crate::quaternion::*;
 
 #[repr(C)]
 #[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
@@ -48,9 +51,31 @@ impl<T> Mat4<T> {
 		- x.w * y.z * z.x * w.y
 	}
 	
+	pub fn minor(self, row: usize, col: usize) -> Mat3<T>
+		where T: Copy {
+		let m = self.into_array();
+		let skip = |i: usize, n: usize| if n < i { n } else { n + 1 };
+		let r = [skip(row, 0), skip(row, 1), skip(row, 2)];
+		let c = [skip(col, 0), skip(col, 1), skip(col, 2)];
+		mat3(
+			vec3(m[r[0]][c[0]], m[r[0]][c[1]], m[r[0]][c[2]]),
+			vec3(m[r[1]][c[0]], m[r[1]][c[1]], m[r[1]][c[2]]),
+			vec3(m[r[2]][c[0]], m[r[2]][c[1]], m[r[2]][c[2]]),
+		)
+	}
+	
 	pub fn cofactor(self) -> Self
 		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
-		unimplemented!()
+		let c = |row: usize, col: usize| {
+			let d = self.minor(row, col).det();
+			if (row + col) % 2 == 1 { -d } else { d }
+		};
+		mat4(
+			vec4(c(0,0), c(0,1), c(0,2), c(0,3)),
+			vec4(c(1,0), c(1,1), c(1,2), c(1,3)),
+			vec4(c(2,0), c(2,1), c(2,2), c(2,3)),
+			vec4(c(3,0), c(3,1), c(3,2), c(3,3)),
+		)
 	}
 	
 	pub fn adjoint(self) -> Self
@@ -60,61 +85,32 @@ impl<T> Mat4<T> {
 	
 	pub fn inv(self) -> Self
 		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
-		let Mat4{ x,y,z,w } = self;
-		mat4(
-			vec4(
-					y.y * z.z * w.w + y.z * z.w * w.y + y.w * z.y * w.z
-				- y.y * z.w * w.z - y.z * z.y * w.w - y.w * z.z * w.y,
-				
-					x.y * z.w * w.z + x.z * z.y * w.w + x.w * z.z * w.y
-				- x.y * z.z * w.w - x.z * z.w * w.y - x.w * z.y * w.z,
-				
-					x.y * y.z * w.w + x.z * y.w * w.y + x.w * y.y * w.z
-				- x.y * y.w * w.z - x.z * y.y * w.w - x.w * y.z * w.y,
-				
-					x.y * y.w * z.z + x.z * y.y * z.w + x.w * y.z * z.y
-				- x.y * y.z * z.w - x.z * y.w * z.y - x.w * y.y * z.z,
-			) / self.det(),
-			vec4(
-					y.x * z.w * w.z + y.z * z.x * w.w + y.w * z.z * w.x
-				- y.x * z.z * w.w - y.z * z.w * w.x - y.w * z.x * w.z,
-				
-					x.x * z.z * w.w + x.z * z.w * w.x + x.w * z.x * w.z
-				- x.x * z.w * w.z - x.z * z.x * w.w - x.w * z.z * w.x,
-				
-					x.x * y.w * w.z + x.z * y.x * w.w + x.w * y.z * w.x
-				- x.x * y.z * w.w - x.z * y.w * w.x - x.w * y.x * w.z,
-				
-					x.x * y.z * z.w + x.z * y.w * z.x + x.w * y.x * z.z
-				- x.x * y.w * z.z - x.z * y.x * z.w - x.w * y.z * z.x,
-			) / self.det(),
-			vec4(
-					y.x * z.y * w.w + y.y * z.w * w.x + y.w * z.x * w.y
-				- y.x * z.w * w.y - y.y * z.x * w.w - y.w * z.y * w.x,
-				
-					x.x * z.w * w.y + x.y * z.x * w.w + x.w * z.y * w.x
-				- x.x * z.y * w.w - x.y * z.w * w.x - x.w * z.x * w.y,
-				
-					x.x * y.y * w.w + x.y * y.w * w.x + x.w * y.x * w.y
-				- x.x * y.w * w.y - x.y * y.x * w.w - x.w * y.y * w.x,
-				
-					x.x * y.w * z.y + x.y * y.x * z.w + x.w * y.y * z.x
-				- x.x * y.y * z.w - x.y * y.w * z.x - x.w * y.x * z.y,
-			) / self.det(),
-			vec4(
-					y.x * z.z * w.y + y.y * z.x * w.z + y.z * z.y * w.x
-				- y.x * z.y * w.z - y.y * z.z * w.x - y.z * z.x * w.y,
-				
-					x.x * z.y * w.z + x.y * z.z * w.x + x.z * z.x * w.y
-				- x.x * z.z * w.y - x.y * z.x * w.z - x.z * z.y * w.x,
-				
-					x.x * y.z * w.y + x.y * y.x * w.z + x.z * y.y * w.x
-				- x.x * y.y * w.z - x.y * y.z * w.x - x.z * y.x * w.y,
-				
-					x.x * y.y * z.z + x.y * y.z * z.x + x.z * y.x * z.y
-				- x.x * y.z * z.y - x.y * y.x * z.z - x.z * y.y * z.x,
-			) / self.det(),
-		)
+		self.inv_with_det().0
+	}
+	
+	pub fn inv_with_det(self) -> (Self, T)
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> {
+		let det = self.det();
+		let Mat4{ x,y,z,w } = self.adjoint();
+		(mat4(x/det,y/det,z/det,w/det), det)
+	}
+	
+	//returns None when the determinant is exactly zero
+	pub fn try_inv(self) -> Option<Self>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> + PartialEq + Zero {
+		let det = self.det();
+		if det == T::zero() { return None; }
+		let Mat4{ x,y,z,w } = self.adjoint();
+		Some(mat4(x/det,y/det,z/det,w/det))
+	}
+	
+	//returns None when |det| <= epsilon, or when the determinant is NaN
+	pub fn try_inv_eps(self, epsilon: T) -> Option<Self>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T> + PartialOrd {
+		let det = self.det();
+		if !(det > epsilon || det < -epsilon) { return None; }
+		let Mat4{ x,y,z,w } = self.adjoint();
+		Some(mat4(x/det,y/det,z/det,w/det))
 	}
 	
 	pub fn ident() -> Self
@@ -127,6 +123,11 @@ impl<T> Mat4<T> {
 		)
 	}
 	
+	pub fn zero() -> Self
+		where T: Zero {
+		mat4(Vec4::zero(), Vec4::zero(), Vec4::zero(), Vec4::zero())
+	}
+	
 	pub fn apply_to(self, v: Vec4<T>) -> Vec4<T>
 		where Vec4<T>: Vector<T> + Copy {
 		vec4(
@@ -137,6 +138,22 @@ impl<T> Mat4<T> {
 		)
 	}
 	
+	//flattened column by column, the order glsl/wgsl/spir-v expect for a matrix
+	pub fn to_cols_array(self) -> [T; 16] {
+		let Mat4{ x,y,z,w } = self;
+		[x.x, y.x, z.x, w.x, x.y, y.y, z.y, w.y, x.z, y.z, z.z, w.z, x.w, y.w, z.w, w.w]
+	}
+	
+	pub fn from_cols_array(a: [T; 16]) -> Self {
+		let [a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p] = a;
+		mat4(
+			vec4(a, e, i, m),
+			vec4(b, f, j, n),
+			vec4(c, g, k, o),
+			vec4(d, h, l, p),
+		)
+	}
+	
 	pub fn transpose(self) -> Self {
 		mat4(
 			vec4(self.x.x, self.y.x, self.z.x, self.w.x),
@@ -146,51 +163,186 @@ impl<T> Mat4<T> {
 		)
 	}
 	
-	pub fn into_array(self) -> [[T; 4]; 4] {	let Mat4{x,y,z,w} = self; [x.into_array(),y.into_array(),z.into_array(),w.into_array()] }
-	pub fn into_tuple(self) -> ((T,T,T,T),(T,T,T,T),(T,T,T,T),(T,T,T,T)) { let Mat4{x,y,z,w} = self; (x.into_tuple(),y.into_tuple(),z.into_tuple(),w.into_tuple()) }
-}
-
-pub fn mat4<T>(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Mat4<T> {
-	Mat4 { x: x, y: y, z: z, w: w }
-}
-
-impl<T> Default for Mat4<T>
-	where T: Zero + One {
-	fn default() -> Self {
-		Mat4::ident()
+	pub fn translation(v: Vec3<T>) -> Self
+		where T: Zero + One {
+		Mat3::ident().extend(v, Vec3::zero(), T::one())
 	}
-}
-
-impl<T> Add<Self> for Mat4<T>
-	where T: Add<Output=T> {
-	type Output = Self;
 	
-	fn add(self, other: Self) -> Self {
-		mat4(self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w)
+	pub fn scale(v: Vec3<T>) -> Self
+		where T: Zero + One {
+		mat4(
+			vec4(v.x, T::zero(), T::zero(), T::zero()),
+			vec4(T::zero(), v.y, T::zero(), T::zero()),
+			vec4(T::zero(), T::zero(), v.z, T::zero()),
+			vec4(T::zero(), T::zero(), T::zero(), T::one()),
+		)
 	}
-}
-
-impl<T> AddAssign<Self> for Mat4<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, other: Self) {
-		*self = *self + other;
+	
+	pub fn from_rotation(m: Mat3<T>) -> Self
+		where T: Zero + One {
+		m.extend(Vec3::zero(), Vec3::zero(), T::one())
+	}
+	
+	pub fn from_quaternion(q: Quaternion<T>) -> Self
+		where T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		Self::from_rotation(q.rot_mat())
+	}
+	
+	//view matrix for a right handed coordinate system, the camera looks down -z
+	pub fn look_at_rh(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self
+		where T: Copy + Zero + One + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let f = (target - eye).normalize();
+		let s = cross(f, up).normalize();
+		let u = cross(s, f);
+		mat4(
+			s.extend(-dot(s, eye)),
+			u.extend(-dot(u, eye)),
+			(-f).extend(dot(f, eye)),
+			vec4(T::zero(), T::zero(), T::zero(), T::one()),
+		)
+	}
+	
+	//view matrix for a left handed coordinate system, the camera looks down +z
+	pub fn look_at_lh(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Self
+		where T: Copy + Zero + One + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let f = (target - eye).normalize();
+		let s = cross(up, f).normalize();
+		let u = cross(f, s);
+		mat4(
+			s.extend(-dot(s, eye)),
+			u.extend(-dot(u, eye)),
+			f.extend(-dot(f, eye)),
+			vec4(T::zero(), T::zero(), T::zero(), T::one()),
+		)
+	}
+	
+	//treats p as a point (w = 1), so translation is applied
+	pub fn transform_point3(self, p: Vec3<T>) -> Vec3<T>
+		where T: One, Vec4<T>: Vector<T> + Copy {
+		self.apply_to(p.extend(T::one())).downsize()
+	}
+	
+	//treats v as a direction (w = 0), so translation is ignored
+	pub fn transform_vector3(self, v: Vec3<T>) -> Vec3<T>
+		where T: Zero, Vec4<T>: Vector<T> + Copy {
+		self.apply_to(v.extend(T::zero())).downsize()
+	}
+	
+	//all projections are right handed (the camera looks down -z, as with look_at_rh)
+	//and map the view frustum to the clip space depth range given by `depth`
+	pub fn perspective(fov_y: T, aspect: T, near: T, far: T, depth: DepthRange) -> Self
+		where T: Copy + Trig + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let f = T::one() / (fov_y / T::two()).tan();
+		mat4(
+			vec4(f / aspect, T::zero(), T::zero(), T::zero()),
+			vec4(T::zero(), f, T::zero(), T::zero()),
+			Self::depth_row(near, far, depth),
+			vec4(T::zero(), T::zero(), -T::one(), T::zero()),
+		)
 	}
-}
-
-impl<T> Sub<Self> for Mat4<T>
-	where T: Sub<Output=T> {
-	type Output = Self;
 	
-	fn sub(self, other: Self) -> Self {
-		mat4(self.x - other.x, self.y - other.y, self.z - other.z, self.w - other.w)
+	pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T, depth: DepthRange) -> Self
+		where T: Copy + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		mat4(
+			vec4(T::two() * near / (right - left), T::zero(), (right + left) / (right - left), T::zero()),
+			vec4(T::zero(), T::two() * near / (top - bottom), (top + bottom) / (top - bottom), T::zero()),
+			Self::depth_row(near, far, depth),
+			vec4(T::zero(), T::zero(), -T::one(), T::zero()),
+		)
 	}
+	
+	pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T, depth: DepthRange) -> Self
+		where T: Copy + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let z = match depth {
+			DepthRange::NegOneToOne => vec4(T::zero(), T::zero(), -T::two() / (far - near), -(far + near) / (far - near)),
+			DepthRange::ZeroToOne => vec4(T::zero(), T::zero(), -T::one() / (far - near), -near / (far - near)),
+		};
+		mat4(
+			vec4(T::two() / (right - left), T::zero(), T::zero(), -(right + left) / (right - left)),
+			vec4(T::zero(), T::two() / (top - bottom), T::zero(), -(top + bottom) / (top - bottom)),
+			z,
+			vec4(T::zero(), T::zero(), T::zero(), T::one()),
+		)
+	}
+	
+	//perspective with the far plane at infinity
+	pub fn perspective_infinite(fov_y: T, aspect: T, near: T, depth: DepthRange) -> Self
+		where T: Copy + Trig + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let f = T::one() / (fov_y / T::two()).tan();
+		let z = match depth {
+			DepthRange::NegOneToOne => vec4(T::zero(), T::zero(), -T::one(), -T::two() * near),
+			DepthRange::ZeroToOne => vec4(T::zero(), T::zero(), -T::one(), -near),
+		};
+		mat4(
+			vec4(f / aspect, T::zero(), T::zero(), T::zero()),
+			vec4(T::zero(), f, T::zero(), T::zero()),
+			z,
+			vec4(T::zero(), T::zero(), -T::one(), T::zero()),
+		)
+	}
+	
+	//reversed-z perspective, the near plane maps to the top of the depth range and far to the bottom
+	pub fn perspective_reversed(fov_y: T, aspect: T, near: T, far: T, depth: DepthRange) -> Self
+		where T: Copy + Trig + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		Self::perspective(fov_y, aspect, near, far, depth).reverse_depth(depth)
+	}
+	
+	pub fn perspective_infinite_reversed(fov_y: T, aspect: T, near: T, depth: DepthRange) -> Self
+		where T: Copy + Trig + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		Self::perspective_infinite(fov_y, aspect, near, depth).reverse_depth(depth)
+	}
+	
+	//flips the clip space depth of a projection matrix within `depth`
+	pub fn reverse_depth(self, depth: DepthRange) -> Self
+		where T: Copy + Neg<Output=T> + Sub<Output=T> {
+		let z = match depth {
+			DepthRange::NegOneToOne => -self.z,
+			DepthRange::ZeroToOne => self.w - self.z,
+		};
+		mat4(self.x, self.y, z, self.w)
+	}
+	
+	fn depth_row(near: T, far: T, depth: DepthRange) -> Vec4<T>
+		where T: Copy + Zero + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		match depth {
+			DepthRange::NegOneToOne => vec4(T::zero(), T::zero(), (far + near) / (near - far), T::two() * far * near / (near - far)),
+			DepthRange::ZeroToOne => vec4(T::zero(), T::zero(), far / (near - far), far * near / (near - far)),
+		}
+	}
+	
+	//treats p as a point (w = 1) and performs the perspective divide on the result
+	pub fn project_point3(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + One + Div<Output=T>, Vec4<T>: Vector<T> + Copy {
+		let v = self.apply_to(p.extend(T::one()));
+		v.downsize() / v.w
+	}
+	
+	//maps a point in normalized device coordinates back through this (view) projection matrix
+	pub fn unproject(self, ndc: Vec3<T>) -> Vec3<T>
+		where T: Copy + One + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T>, Vec4<T>: Vector<T> + Copy {
+		self.inv().project_point3(ndc)
+	}
+	
+	//like unproject, but for an unnormalized clip space point
+	pub fn unproject_clip(self, clip: Vec4<T>) -> Vec3<T>
+		where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> + Neg<Output=T>, Vec4<T>: Vector<T> + Copy {
+		let v = self.inv().apply_to(clip);
+		v.downsize() / v.w
+	}
+	
+	pub fn into_array(self) -> [[T; 4]; 4] {	let Mat4{x,y,z,w} = self; [x.into_array(),y.into_array(),z.into_array(),w.into_array()] }
+	pub fn into_tuple(self) -> ((T,T,T,T),(T,T,T,T),(T,T,T,T),(T,T,T,T)) { let Mat4{x,y,z,w} = self; (x.into_tuple(),y.into_tuple(),z.into_tuple(),w.into_tuple()) }
 }
 
-impl<T> SubAssign<Self> for Mat4<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, other: Self) {
-		*self = *self - other;
-	}
+//clip space depth convention of a projection matrix
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum DepthRange {
+	NegOneToOne, //OpenGL
+	ZeroToOne, //Vulkan, Direct3D, Metal, WebGPU
+}
+
+pub fn mat4<T>(x: Vec4<T>, y: Vec4<T>, z: Vec4<T>, w: Vec4<T>) -> Mat4<T> {
+	Mat4 { x: x, y: y, z: z, w: w }
 }
 
 impl<T> Mul<Mat4<T>> for Mat4<T>
@@ -218,9 +370,61 @@ impl<T> Mul<Vec4<T>> for Mat4<T>
 	}
 }
 
-impl<T: Neg> Neg for Mat4<T> {
-	type Output = Mat4<<T as Neg>::Output>;
-	fn neg(self) -> Mat4<<T as Neg>::Output> { mat4(-self.x,-self.y,-self.z,-self.w) }
+impl<T> MulAssign<Mat4<T>> for Mat4<T>
+	where T: Copy, Vec4<T>: Vector<T> + Copy {
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+//row vector times matrix
+impl<T> Mul<Mat4<T>> for Vec4<T>
+	where Vec4<T>: Vector<T> + Copy {
+	type Output = Vec4<T>;
+	
+	fn mul(self, m: Mat4<T>) -> Vec4<T> {
+		m.transpose().apply_to(self)
+	}
+}
+
+impl<T> MulAssign<Mat4<T>> for Vec4<T>
+	where Vec4<T>: Vector<T> + Copy {
+	fn mul_assign(&mut self, m: Mat4<T>) {
+		*self = *self * m;
+	}
+}
+
+impl<T: Zero + One> Product<Mat4<T>> for Mat4<T>
+	where Vec4<T>: Vector<T> + Copy {
+	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::ident(), |a, b| a * b)
+	}
+}
+
+impl<T> Index<usize> for Mat4<T> {
+	type Output = Vec4<T>;
+	
+	fn index(&self, index: usize) -> &Vec4<T> {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			2 => &self.z,
+			3 => &self.w,
+			_ => panic!("index out of bounds, index is {} but the len is 4",index),
+		}
+	}
+}
+
+impl<T> IndexMut<usize> for Mat4<T> {
+	fn index_mut(&mut self, index: usize) -> &mut Vec4<T> {
+		match index {
+			0 => &mut self.x,
+			1 => &mut self.y,
+			2 => &mut self.z,
+			3 => &mut self.w,
+			_ => panic!("index out of bounds, index is {} but the len is 4",index),
+		}
+	}
 }
 
 impl<T> ArrayTuple for Mat4<T> {
@@ -230,26 +434,17 @@ impl<T> ArrayTuple for Mat4<T> {
 	fn into_tuple(self) -> Self::Tuple { let Mat4{x,y,z,w} = self; (x.into_tuple(),y.into_tuple(),z.into_tuple(),w.into_tuple()) }
 }
 
-macro convert($T: ty, $($U: ident),*) {
-	$(
-		impl Mat4<$T> {
-			pub fn $U(self) -> Mat4<$U> {
-				mat4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-	)*
+impl<T> From<((T,T,T,T),(T,T,T,T),(T,T,T,T),(T,T,T,T))> for Mat4<T> {
+	fn from(t: ((T,T,T,T),(T,T,T,T),(T,T,T,T),(T,T,T,T))) -> Self {
+		let (x,y,z,w) = t;
+		mat4(x.into(), y.into(), z.into(), w.into())
+	}
+}
+
+impl<T> From<[[T; 4]; 4]> for Mat4<T> {
+	fn from(a: [[T; 4]; 4]) -> Self {
+		let [x,y,z,w] = a;
+		mat4(x.into(), y.into(), z.into(), w.into())
+	}
 }
 
-convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
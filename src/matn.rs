@@ -0,0 +1,496 @@
+use crate::prelude::*;
+
+use crate::vecn::*;
+use crate::mat2::*;
+use crate::mat3::*;
+use crate::mat4::*;
+
+use std::array;
+use std::marker::PhantomData;
+use serde::{Serializer,Deserializer};
+use serde::ser::SerializeTuple;
+use serde::de::{self,Visitor,SeqAccess};
+
+//R rows of C columns, stored row by row like Mat2/Mat3/Mat4
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct Mat<T, const R: usize, const C: usize>(pub [VecN<T, C>; R]);
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C> {
+	pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Mat<U, R, C> {
+		Mat(self.0.map(|row| row.map(&mut f)))
+	}
+	
+	pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: Mat<U, R, C>, mut f: F) -> Mat<V, R, C> {
+		let mut i = IntoIterator::into_iter(self.0).zip(other.0);
+		Mat(array::from_fn(|_| { let (a, b) = i.next().unwrap(); a.zip_with(b, &mut f) }))
+	}
+	
+	pub fn zero() -> Self
+		where T: Zero {
+		Mat(array::from_fn(|_| VecN::zero()))
+	}
+	
+	pub fn row(self, r: usize) -> VecN<T, C> {
+		let Mat(rows) = self;
+		IntoIterator::into_iter(rows).nth(r).expect("row index out of bounds")
+	}
+	
+	pub fn col(self, c: usize) -> VecN<T, R>
+		where T: Copy {
+		VecN(array::from_fn(|r| self.0[r][c]))
+	}
+	
+	pub fn transpose(self) -> Mat<T, C, R>
+		where T: Copy {
+		Mat(array::from_fn(|c| self.col(c)))
+	}
+	
+	pub fn apply_to(self, v: VecN<T, C>) -> VecN<T, R>
+		where T: Copy + Zero + Mul<Output=T> + Add<Output=T> {
+		VecN(self.0.map(|row| (row * v).sum_elem()))
+	}
+	
+	pub fn convert<U>(self) -> Mat<U, R, C>
+		where T: Into<U> {
+		self.map(T::into)
+	}
+}
+
+impl<T, const N: usize> Mat<T, N, N> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		Mat(array::from_fn(|r| VecN(array::from_fn(|c| if r == c { T::one() } else { T::zero() }))))
+	}
+}
+
+pub fn matn<T, const R: usize, const C: usize>(rows: [VecN<T, C>; R]) -> Mat<T, R, C> {
+	Mat(rows)
+}
+
+impl<T, const N: usize> Default for Mat<T, N, N>
+	where T: Zero + One {
+	fn default() -> Self {
+		Mat::ident()
+	}
+}
+
+impl<T, const R: usize, const C: usize> Add<Self> for Mat<T, R, C>
+	where T: Add<Output=T> {
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		self.zip_with(other, |a, b| a + b)
+	}
+}
+
+impl<T, const R: usize, const C: usize> AddAssign<Self> for Mat<T, R, C>
+	where T: Copy + Add<Output=T> {
+	fn add_assign(&mut self, other: Self) {
+		*self = *self + other;
+	}
+}
+
+impl<T, const R: usize, const C: usize> Sub<Self> for Mat<T, R, C>
+	where T: Sub<Output=T> {
+	type Output = Self;
+	
+	fn sub(self, other: Self) -> Self {
+		self.zip_with(other, |a, b| a - b)
+	}
+}
+
+impl<T, const R: usize, const C: usize> SubAssign<Self> for Mat<T, R, C>
+	where T: Copy + Sub<Output=T> {
+	fn sub_assign(&mut self, other: Self) {
+		*self = *self - other;
+	}
+}
+
+//(R x C) * (C x K) = (R x K)
+impl<T, const R: usize, const C: usize, const K: usize> Mul<Mat<T, C, K>> for Mat<T, R, C>
+	where T: Copy + Zero + Mul<Output=T> + Add<Output=T> {
+	type Output = Mat<T, R, K>;
+	
+	fn mul(self, other: Mat<T, C, K>) -> Mat<T, R, K> {
+		let t = other.transpose();
+		Mat(self.0.map(|row| t.apply_to(row)))
+	}
+}
+
+impl<T, const N: usize> MulAssign<Self> for Mat<T, N, N>
+	where T: Copy + Zero + Mul<Output=T> + Add<Output=T> {
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+impl<T, const R: usize, const C: usize> Mul<VecN<T, C>> for Mat<T, R, C>
+	where T: Copy + Zero + Mul<Output=T> + Add<Output=T> {
+	type Output = VecN<T, R>;
+	
+	fn mul(self, v: VecN<T, C>) -> VecN<T, R> {
+		self.apply_to(v)
+	}
+}
+
+impl<T, const R: usize, const C: usize> Mul<T> for Mat<T, R, C>
+	where T: Copy + Mul<Output=T> {
+	type Output = Self;
+	
+	fn mul(self, scalar: T) -> Self {
+		self.map(|a| a * scalar)
+	}
+}
+
+impl<T, const R: usize, const C: usize> Div<T> for Mat<T, R, C>
+	where T: Copy + Div<Output=T> {
+	type Output = Self;
+	
+	fn div(self, scalar: T) -> Self {
+		self.map(|a| a / scalar)
+	}
+}
+
+impl<T, const R: usize, const C: usize> Rem<T> for Mat<T, R, C>
+	where T: Copy + Rem<Output=T> {
+	type Output = Self;
+	
+	fn rem(self, scalar: T) -> Self {
+		self.map(|a| a % scalar)
+	}
+}
+
+impl<T, const R: usize, const C: usize> MulAssign<T> for Mat<T, R, C>
+	where T: Copy + Mul<Output=T> {
+	fn mul_assign(&mut self, scalar: T) {
+		*self = *self * scalar;
+	}
+}
+
+impl<T, const R: usize, const C: usize> DivAssign<T> for Mat<T, R, C>
+	where T: Copy + Div<Output=T> {
+	fn div_assign(&mut self, scalar: T) {
+		*self = *self / scalar;
+	}
+}
+
+impl<T, const R: usize, const C: usize> RemAssign<T> for Mat<T, R, C>
+	where T: Copy + Rem<Output=T> {
+	fn rem_assign(&mut self, scalar: T) {
+		*self = *self % scalar;
+	}
+}
+
+impl<T: Neg, const R: usize, const C: usize> Neg for Mat<T, R, C> {
+	type Output = Mat<<T as Neg>::Output, R, C>;
+	fn neg(self) -> Mat<<T as Neg>::Output, R, C> { self.map(|a| -a) }
+}
+
+impl<T: Add<Output=T> + Zero, const R: usize, const C: usize> Sum<Mat<T, R, C>> for Mat<T, R, C> {
+	fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::zero(), |a, b| a + b)
+	}
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Add<Output=T>, const N: usize> Product<Mat<T, N, N>> for Mat<T, N, N> {
+	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::ident(), |a, b| a * b)
+	}
+}
+
+impl<T, const R: usize, const C: usize> Index<usize> for Mat<T, R, C> {
+	type Output = VecN<T, C>;
+	
+	fn index(&self, index: usize) -> &VecN<T, C> {
+		&self.0[index]
+	}
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<usize> for Mat<T, R, C> {
+	fn index_mut(&mut self, index: usize) -> &mut VecN<T, C> {
+		&mut self.0[index]
+	}
+}
+
+impl<T, const R: usize, const C: usize> ArrayTuple for Mat<T, R, C>
+	where VecN<T, C>: ArrayTuple<Array=[T; C]>, [<VecN<T, C> as ArrayTuple>::Tuple; R]: ArrayTuple {
+	type Array = [[T; C]; R];
+	type Tuple = <[<VecN<T, C> as ArrayTuple>::Tuple; R] as ArrayTuple>::Tuple;
+	fn into_array(self) -> Self::Array { self.0.map(|row| row.into_array()) }
+	fn into_tuple(self) -> Self::Tuple { self.0.map(|row| row.into_tuple()).into_tuple() }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Mat<T, R, C> {
+	fn from(a: [[T; C]; R]) -> Self {
+		Mat(a.map(VecN))
+	}
+}
+
+//the element-wise operators, formatting and parsing of the named matrices go through Mat
+//the matrix products stay with each type
+macro impl_named($($M: ident, $N: expr, $f: ident => ($($r: ident),*));*) {
+	$(
+		impl<T> From<$M<T>> for Mat<T, $N, $N> {
+			fn from(m: $M<T>) -> Self {
+				let $M{ $($r),* } = m;
+				Mat([$($r.into()),*])
+			}
+		}
+		
+		impl<T> From<Mat<T, $N, $N>> for $M<T> {
+			fn from(m: Mat<T, $N, $N>) -> Self {
+				let [$($r),*] = m.0;
+				$f($($r.into()),*)
+			}
+		}
+		
+		impl<'a, T> From<&'a $M<T>> for Mat<&'a T, $N, $N> {
+			fn from(m: &'a $M<T>) -> Self {
+				Mat([$(VecN::from(&m.$r)),*])
+			}
+		}
+		
+		impl<T> Default for $M<T>
+			where T: Zero + One {
+			fn default() -> Self {
+				Mat::<T, $N, $N>::default().into()
+			}
+		}
+		
+		impl<T> Add<Self> for $M<T>
+			where T: Add<Output=T> {
+			type Output = Self;
+			
+			fn add(self, other: Self) -> Self {
+				(Mat::<T, $N, $N>::from(self) + Mat::from(other)).into()
+			}
+		}
+		
+		impl<T> AddAssign<Self> for $M<T>
+			where T: Copy + Add<Output=T> {
+			fn add_assign(&mut self, other: Self) {
+				*self = *self + other;
+			}
+		}
+		
+		impl<T> Sub<Self> for $M<T>
+			where T: Sub<Output=T> {
+			type Output = Self;
+			
+			fn sub(self, other: Self) -> Self {
+				(Mat::<T, $N, $N>::from(self) - Mat::from(other)).into()
+			}
+		}
+		
+		impl<T> SubAssign<Self> for $M<T>
+			where T: Copy + Sub<Output=T> {
+			fn sub_assign(&mut self, other: Self) {
+				*self = *self - other;
+			}
+		}
+		
+		impl<T> Mul<T> for $M<T>
+			where T: Copy + Mul<Output=T> {
+			type Output = Self;
+			
+			fn mul(self, scalar: T) -> Self {
+				(Mat::<T, $N, $N>::from(self) * scalar).into()
+			}
+		}
+		
+		impl<T> MulAssign<T> for $M<T>
+			where T: Copy + Mul<Output=T> {
+			fn mul_assign(&mut self, scalar: T) {
+				*self = *self * scalar;
+			}
+		}
+		
+		impl<T> Div<T> for $M<T>
+			where T: Copy + Div<Output=T> {
+			type Output = Self;
+			
+			fn div(self, scalar: T) -> Self {
+				(Mat::<T, $N, $N>::from(self) / scalar).into()
+			}
+		}
+		
+		impl<T> DivAssign<T> for $M<T>
+			where T: Copy + Div<Output=T> {
+			fn div_assign(&mut self, scalar: T) {
+				*self = *self / scalar;
+			}
+		}
+		
+		impl<T> Rem<T> for $M<T>
+			where T: Copy + Rem<Output=T> {
+			type Output = Self;
+			
+			fn rem(self, scalar: T) -> Self {
+				(Mat::<T, $N, $N>::from(self) % scalar).into()
+			}
+		}
+		
+		impl<T> RemAssign<T> for $M<T>
+			where T: Copy + Rem<Output=T> {
+			fn rem_assign(&mut self, scalar: T) {
+				*self = *self % scalar;
+			}
+		}
+		
+		impl<T: Neg> Neg for $M<T> {
+			type Output = $M<<T as Neg>::Output>;
+			fn neg(self) -> $M<<T as Neg>::Output> { (-Mat::<T, $N, $N>::from(self)).into() }
+		}
+		
+		impl<T: Add<Output=T> + Zero> Sum<$M<T>> for $M<T> {
+			fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+				iter.map(Mat::<T, $N, $N>::from).sum::<Mat<T, $N, $N>>().into()
+			}
+		}
+		
+		impl<T: fmt::Display> fmt::Display for $M<T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::Display::fmt(&Mat::from(self), f)
+			}
+		}
+		
+		impl<T: NiceFmt> NiceFmt for $M<T> {
+			fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+				Mat::from(self).nice_fmt(limit, pad)
+			}
+		}
+		
+		impl<T: fmt::LowerExp> fmt::LowerExp for $M<T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::LowerExp::fmt(&Mat::from(self), f)
+			}
+		}
+		
+		impl<T: FromStr> FromStr for $M<T> {
+			type Err = <T as FromStr>::Err;
+			
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				s.parse::<Mat<T, $N, $N>>().map(Self::from)
+			}
+		}
+	)*
+}
+
+impl_named!(Mat2, 2, mat2 => (x, y); Mat3, 3, mat3 => (x, y, z); Mat4, 4, mat4 => (x, y, z, w));
+
+macro impl_convert($($T: ty => $($U: ident),*);*) {
+	$($(
+		impl<const R: usize, const C: usize> Mat<$T, R, C> {
+			pub fn $U(self) -> Mat<$U, R, C> {
+				self.map(|a| a as $U)
+			}
+		}
+		impl Mat2<$T> {
+			pub fn $U(self) -> Mat2<$U> {
+				Mat::<$T, 2, 2>::from(self).$U().into()
+			}
+		}
+		impl Mat3<$T> {
+			pub fn $U(self) -> Mat3<$U> {
+				Mat::<$T, 3, 3>::from(self).$U().into()
+			}
+		}
+		impl Mat4<$T> {
+			pub fn $U(self) -> Mat4<$U> {
+				Mat::<$T, 4, 4>::from(self).$U().into()
+			}
+		}
+	)*)*
+}
+
+impl_convert!(
+	u8 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	u16 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	u32 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	u64 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	usize => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i8 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i16 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i32 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i64 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	isize => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	f32 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	f64 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	bool => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize
+);
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C> {
+	fn grid<F: Fn(&T) -> String>(&self, cell: F) -> String {
+		fmt_grid(self.0.iter().map(|row| row.0.iter().map(&cell).collect()).collect())
+	}
+}
+
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Mat<T, R, C> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let p = f.precision();
+		write!(f, "{}", self.grid(|a| if let Some(p) = p { format!("{:.*}", p, a) } else { format!("{}", a) }))
+	}
+}
+
+impl<T: NiceFmt, const R: usize, const C: usize> NiceFmt for Mat<T, R, C> {
+	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+		self.grid(|a| a.nice_fmt(limit, pad))
+	}
+}
+
+impl<T: fmt::LowerExp, const R: usize, const C: usize> fmt::LowerExp for Mat<T, R, C> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let p = f.precision();
+		write!(f, "{}", self.grid(|a| if let Some(p) = p { format!("{:.*e}", p, a) } else { format!("{:e}", a) }))
+	}
+}
+
+impl<T: FromStr, const R: usize, const C: usize> FromStr for Mat<T, R, C> {
+	type Err = <T as FromStr>::Err;
+	
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut n: Vec<&str> = s.split(|c: char| c.is_whitespace() || c == ',')
+			.map(|s| s.trim_matches(BRACKETS)).filter(|s| !s.is_empty()).collect();
+		while n.len() < R * C { n.push(""); }
+		let parsed = n[..R * C].iter().map(|s| s.parse()).collect::<Result<Vec<T>, _>>()?;
+		let mut i = parsed.into_iter();
+		Ok(Mat(array::from_fn(|_| VecN(array::from_fn(|_| i.next().unwrap())))))
+	}
+}
+
+//written out as a tuple of R rows, see the VecN impls
+impl<T: Serialize, const R: usize, const C: usize> Serialize for Mat<T, R, C> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut t = serializer.serialize_tuple(R)?;
+		for row in self.0.iter() {
+			t.serialize_element(row)?;
+		}
+		t.end()
+	}
+}
+
+impl<'de, T: Deserialize<'de>, const R: usize, const C: usize> Deserialize<'de> for Mat<T, R, C> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct MatVisitor<T, const R: usize, const C: usize>(PhantomData<T>);
+		
+		impl<'de, T: Deserialize<'de>, const R: usize, const C: usize> Visitor<'de> for MatVisitor<T, R, C> {
+			type Value = Mat<T, R, C>;
+			
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				write!(f, "a tuple of {} rows", R)
+			}
+			
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Mat<T, R, C>, A::Error> {
+				let mut rows = Vec::with_capacity(R);
+				for i in 0..R {
+					rows.push(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?);
+				}
+				let mut i = rows.into_iter();
+				Ok(Mat(array::from_fn(|_| i.next().unwrap())))
+			}
+		}
+		
+		deserializer.deserialize_tuple(R, MatVisitor(PhantomData))
+	}
+}
@@ -0,0 +1,205 @@
+use crate::prelude::*;
+
+use crate::vec3::*;
+use crate::vec4::*;
+use crate::mat4::*;
+use crate::aabb::*;
+use crate::shapes::*;
+
+//how two overlapping shapes are touching: moving the second one by depth along normal
+//(or the first one by depth against it) separates them, touching shapes give a depth of 0
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Contact<T> {
+	pub normal: Vec3<T>,
+	pub depth: T,
+}
+
+fn axis<T>(i: usize, positive: bool) -> Vec3<T>
+	where T: Zero + One + Neg<Output=T> {
+	let one = if positive { T::one() } else { -T::one() };
+	match i {
+		0 => vec3(one, T::zero(), T::zero()),
+		1 => vec3(T::zero(), one, T::zero()),
+		_ => vec3(T::zero(), T::zero(), one),
+	}
+}
+
+//separating axis test: project_a and project_b give the interval (min, max) each shape covers along an axis
+//the axes don't have to be unit length, zero length ones (from crossing parallel edges) are skipped
+//None as soon as one axis separates the shapes, otherwise the contact along the axis needing the smallest push
+pub fn sat<T, A, B>(axes: &[Vec3<T>], project_a: A, project_b: B) -> Option<Contact<T>>
+	where
+		T: Copy + PartialOrd + Zero + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T>,
+		A: Fn(Vec3<T>) -> (T, T),
+		B: Fn(Vec3<T>) -> (T, T) {
+	let mut best: Option<Contact<T>> = None;
+	for &l in axes {
+		if dot(l, l) == T::zero() { continue; }
+		let l = l.normalize();
+		let ((a_min, a_max), (b_min, b_max)) = (project_a(l), project_b(l));
+		//how far b has to move along l or against it to clear a, the smaller one is the overlap
+		let (forward, back) = (a_max - b_min, b_max - a_min);
+		if forward < T::zero() || back < T::zero() || forward.is_nan() || back.is_nan() { return None; }
+		let (depth, normal) = if forward <= back { (forward, l) } else { (back, -l) };
+		if best.is_none_or(|c| depth < c.depth) { best = Some(Contact{ normal, depth }); }
+	}
+	best
+}
+
+pub fn sphere_sphere<T>(a: Sphere<T>, b: Sphere<T>) -> Option<Contact<T>>
+	where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> {
+	let d = b.center - a.center;
+	let dist = d.magnitude();
+	let depth = a.radius + b.radius - dist;
+	if depth < T::zero() || depth.is_nan() { return None; }
+	//concentric spheres can be pushed apart in any direction
+	let normal = if dist == T::zero() { axis(1, true) } else { d / dist };
+	Some(Contact{ normal, depth })
+}
+
+pub fn sphere_aabb<T>(s: Sphere<T>, b: Aabb3<T>) -> Option<Contact<T>>
+	where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> {
+	let q = b.closest_point(s.center);
+	let d = q - s.center;
+	let dist = d.magnitude();
+	if dist > T::zero() {
+		let depth = s.radius - dist;
+		if depth < T::zero() || depth.is_nan() { return None; }
+		return Some(Contact{ normal: d / dist, depth });
+	}
+	if !b.contains_point(s.center) { return None; }
+	//the center is inside the box, so the sphere leaves through the nearest face
+	let (c, lo, hi) = (s.center.into_array(), b.min.into_array(), b.max.into_array());
+	let mut best = (c[0] - lo[0], 0, false);
+	for i in 0..3 {
+		for &(gap, to_max) in &[(c[i] - lo[i], false), (hi[i] - c[i], true)] {
+			if gap < best.0 { best = (gap, i, to_max); }
+		}
+	}
+	let (gap, i, to_max) = best;
+	//the sphere moves out through that face, so the box moves the opposite way
+	Some(Contact{ normal: axis(i, !to_max), depth: gap + s.radius })
+}
+
+pub fn aabb_aabb<T>(a: Aabb3<T>, b: Aabb3<T>) -> Option<Contact<T>>
+	where T: Copy + PartialOrd + Zero + One + Add<Output=T> + Sub<Output=T> + Neg<Output=T> {
+	let (a_min, a_max, b_min, b_max) = (a.min.into_array(), a.max.into_array(), b.min.into_array(), b.max.into_array());
+	let mut best: Option<Contact<T>> = None;
+	for i in 0..3 {
+		//as in sat, along the axis or against it
+		let (forward, back) = (a_max[i] - b_min[i], b_max[i] - a_min[i]);
+		if forward < T::zero() || back < T::zero() || forward.is_nan() || back.is_nan() { return None; }
+		let (depth, positive) = if forward <= back { (forward, true) } else { (back, false) };
+		if best.is_none_or(|c| depth < c.depth) { best = Some(Contact{ normal: axis(i, positive), depth }); }
+	}
+	best
+}
+
+//the 15 axes: the 3 face normals of each box and the 9 cross products of their edges
+pub fn obb_obb<T>(a: Obb3<T>, b: Obb3<T>) -> Option<Contact<T>>
+	where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> {
+	let (ta, tb) = (a.axes.transpose(), b.axes.transpose());
+	let (ea, eb) = ([ta.x, ta.y, ta.z], [tb.x, tb.y, tb.z]);
+	let mut axes = vec![ea[0], ea[1], ea[2], eb[0], eb[1], eb[2]];
+	for &i in &ea {
+		for &j in &eb {
+			axes.push(cross(i, j));
+		}
+	}
+	let project = |o: Obb3<T>, edges: [Vec3<T>; 3]| move |l: Vec3<T>| {
+		let c = dot(o.center, l);
+		let h = o.half_extents;
+		let r = h.x * abs(dot(edges[0], l)) + h.y * abs(dot(edges[1], l)) + h.z * abs(dot(edges[2], l));
+		(c - r, c + r)
+	};
+	sat(&axes, project(a, ea), project(b, eb))
+}
+
+//the 13 axes: the box's face normals, the triangle's normal and the 9 cross products of their edges
+pub fn triangle_aabb<T>(t: Triangle3<T>, b: Aabb3<T>) -> Option<Contact<T>>
+	where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> {
+	let edges = [t.b - t.a, t.c - t.b, t.a - t.c];
+	let box_axes = [axis(0, true), axis(1, true), axis(2, true)];
+	let mut axes = box_axes.to_vec();
+	axes.push(cross(edges[0], edges[1]));
+	for &e in &edges {
+		for &f in &box_axes {
+			axes.push(cross(e, f));
+		}
+	}
+	let (center, h) = (b.center(), b.half_extents());
+	let project_tri = |l: Vec3<T>| {
+		let (p, q, r) = (dot(t.a, l), dot(t.b, l), dot(t.c, l));
+		(p.non_nan_min(q).non_nan_min(r), p.non_nan_max(q).non_nan_max(r))
+	};
+	let project_box = |l: Vec3<T>| {
+		let c = dot(center, l);
+		let r = h.x * abs(l.x) + h.y * abs(l.y) + h.z * abs(l.z);
+		(c - r, c + r)
+	};
+	sat(&axes, project_tri, project_box)
+}
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum Containment {
+	Outside,
+	Intersects,
+	Inside,
+}
+
+//six planes with normals pointing into the frustum, in the order left, right, bottom, top, near, far
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Frustum<T> {
+	pub planes: [Plane<T>; 6],
+}
+
+impl<T> Frustum<T> {
+	//extracts the planes from a (view) projection matrix (Gribb and Hartmann), giving world space planes for a view projection
+	//the far plane of an infinite projection has a zero normal and is kept as is, which puts every point inside it
+	pub fn from_mat4(m: Mat4<T>, depth: DepthRange) -> Self
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Mat4{ x,y,z,w } = m;
+		let near = match depth {
+			DepthRange::NegOneToOne => w + z,
+			DepthRange::ZeroToOne => z,
+		};
+		let planes = [w + x, w - x, w + y, w - y, near, w - z].map(|v: Vec4<T>| {
+			let p = plane(v.downsize(), v.w);
+			if p.normal == Vec3::zero() { p } else { p.normalize() }
+		});
+		Frustum{ planes }
+	}
+	
+	pub fn contains_point(self, p: Vec3<T>) -> bool
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self.planes.iter().all(|pl| pl.signed_distance(p) >= T::zero())
+	}
+	
+	pub fn test_sphere(self, s: Sphere<T>) -> Containment
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Neg<Output=T> {
+		let mut result = Containment::Inside;
+		for pl in self.planes.iter() {
+			let d = pl.signed_distance(s.center);
+			if d < -s.radius { return Containment::Outside; }
+			if d < s.radius { result = Containment::Intersects; }
+		}
+		result
+	}
+	
+	//conservative: a box near a corner of the frustum can be reported as intersecting while being outside
+	pub fn test_aabb(self, b: Aabb3<T>) -> Containment
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let mut result = Containment::Inside;
+		for pl in self.planes.iter() {
+			//the corners furthest along and against the plane normal
+			let n = pl.normal;
+			let pick = |a: T, lo: T, hi: T| if a >= T::zero() { (hi, lo) } else { (lo, hi) };
+			let ((px, nx), (py, ny), (pz, nz)) = (pick(n.x, b.min.x, b.max.x), pick(n.y, b.min.y, b.max.y), pick(n.z, b.min.z, b.max.z));
+			if pl.signed_distance(vec3(px, py, pz)) < T::zero() { return Containment::Outside; }
+			if pl.signed_distance(vec3(nx, ny, nz)) < T::zero() { result = Containment::Intersects; }
+		}
+		result
+	}
+}
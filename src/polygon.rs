@@ -0,0 +1,274 @@
+use crate::prelude::*;
+
+use crate::vec2::*;
+
+use std::cmp::Ordering;
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum Orientation {
+	Clockwise,
+	Collinear,
+	CounterClockwise,
+}
+
+//which way a -> b -> c turns, computed without rounding errors
+pub trait Orient2d: Sized {
+	fn orient2d(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Orientation;
+}
+
+pub fn orient2d<T: Orient2d>(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Orientation {
+	T::orient2d(a, b, c)
+}
+
+fn orientation<T: PartialOrd + Zero>(det: T) -> Orientation {
+	if det > T::zero() {
+		Orientation::CounterClockwise
+	} else if det < T::zero() {
+		Orientation::Clockwise
+	} else {
+		Orientation::Collinear
+	}
+}
+
+//i128 holds the products of any two differences of i32s exactly
+macro impl_orient_ints($($t: ty),*) {
+	$(
+		impl Orient2d for $t {
+			fn orient2d(a: Vec2<$t>, b: Vec2<$t>, c: Vec2<$t>) -> Orientation {
+				let (ax, ay) = (a.x as i128 - c.x as i128, a.y as i128 - c.y as i128);
+				let (bx, by) = (b.x as i128 - c.x as i128, b.y as i128 - c.y as i128);
+				orientation(ax * by - ay * bx)
+			}
+		}
+	)*
+}
+
+impl_orient_ints!(i8,i16,i32);
+
+//differences of i64s need 65 bits so their products can pass i128,
+//the two products are compared as a sign and a u128 magnitude instead of being subtracted
+impl Orient2d for i64 {
+	fn orient2d(a: Vec2<i64>, b: Vec2<i64>, c: Vec2<i64>) -> Orientation {
+		let (ax, ay) = (a.x as i128 - c.x as i128, a.y as i128 - c.y as i128);
+		let (bx, by) = (b.x as i128 - c.x as i128, b.y as i128 - c.y as i128);
+		let product = |p: i128, q: i128| (p.signum() * q.signum(), p.unsigned_abs() * q.unsigned_abs());
+		let ((sl, ml), (sr, mr)) = (product(ax, by), product(ay, bx));
+		let order = if sl != sr { sl.cmp(&sr) } else if sl > 0 { ml.cmp(&mr) } else { mr.cmp(&ml) };
+		match order {
+			Ordering::Greater => Orientation::CounterClockwise,
+			Ordering::Less => Orientation::Clockwise,
+			Ordering::Equal => Orientation::Collinear,
+		}
+	}
+}
+
+//x + y = a + b exactly
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+	let x = a + b;
+	let bv = x - a;
+	let av = x - bv;
+	(x, (a - av) + (b - bv))
+}
+
+//x + y = a * b exactly
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+	let x = a * b;
+	(x, a.mul_add(b, -x))
+}
+
+//adaptive like Shewchuk's predicates: the plain floating point determinant is used when its error bound
+//shows the sign is right, otherwise it is summed again exactly as an expansion of non overlapping doubles
+//the inputs are expected to be finite
+impl Orient2d for f64 {
+	fn orient2d(a: Vec2<f64>, b: Vec2<f64>, c: Vec2<f64>) -> Orientation {
+		let left = (a.x - c.x) * (b.y - c.y);
+		let right = (a.y - c.y) * (b.x - c.x);
+		let det = left - right;
+		let bound = (3.0 + 16.0 * f64::EPSILON) * f64::EPSILON * (left.abs() + right.abs());
+		if det > bound || -det > bound {
+			return orientation(det);
+		}
+		//a.x b.y - a.y b.x + b.x c.y - b.y c.x + c.x a.y - c.y a.x, every product split into two exact parts
+		let products = [(a.x, b.y), (-a.y, b.x), (b.x, c.y), (-b.y, c.x), (c.x, a.y), (-c.y, a.x)];
+		let mut expansion: Vec<f64> = Vec::with_capacity(12);
+		for &(p, q) in products.iter() {
+			let (x, y) = two_product(p, q);
+			for &term in &[y, x] {
+				//grow the expansion by one term, it stays sorted by magnitude
+				let mut q = term;
+				for e in expansion.iter_mut() {
+					let (sum, err) = two_sum(q, *e);
+					*e = err;
+					q = sum;
+				}
+				expansion.push(q);
+			}
+		}
+		//the largest non zero component decides the sign
+		orientation(expansion.iter().rev().copied().find(|&e| e != 0.0).unwrap_or(0.0))
+	}
+}
+
+//every f32 is exactly representable as an f64
+impl Orient2d for f32 {
+	fn orient2d(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Orientation {
+		let f = |v: Vec2<f32>| vec2(v.x as f64, v.y as f64);
+		f64::orient2d(f(a), f(b), f(c))
+	}
+}
+
+//a closed polygon, the last point connects back to the first
+#[derive(Debug,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Polygon2<T> {
+	pub points: Vec<Vec2<T>>,
+}
+
+pub fn polygon2<T>(points: Vec<Vec2<T>>) -> Polygon2<T> {
+	Polygon2{ points }
+}
+
+impl<T> Polygon2<T> {
+	pub fn edges(&self) -> impl Iterator<Item=(Vec2<T>, Vec2<T>)> + '_
+		where T: Copy {
+		let n = self.points.len();
+		(0..n).map(move |i| (self.points[i], self.points[(i + 1) % n]))
+	}
+	
+	//exact for integers, where signed_area would round, as long as nothing overflows: with coordinates within ±m
+	//each of the n terms is up to 2m², so i32 is safe for m up to 2^15 / sqrt(n) and i64 up to 2^31 / sqrt(n)
+	pub fn twice_signed_area(&self) -> T
+		where T: Copy + Zero + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self.edges().fold(T::zero(), |sum, (a, b)| sum + perp_dot(a, b))
+	}
+	
+	//positive for counter clockwise polygons
+	pub fn signed_area(&self) -> T
+		where T: Copy + Zero + Two + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self.twice_signed_area() / T::two()
+	}
+	
+	pub fn area(&self) -> T
+		where T: Copy + PartialOrd + Zero + Two + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		abs(self.signed_area())
+	}
+	
+	pub fn winding(&self) -> Orientation
+		where T: Copy + PartialOrd + Zero + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		orientation(self.twice_signed_area())
+	}
+	
+	//the center of mass of the enclosed area, None if there is no area
+	pub fn centroid(&self) -> Option<Vec2<T>>
+		where T: Copy + PartialEq + Zero + One + Two + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let twice_area = self.twice_signed_area();
+		if twice_area == T::zero() { return None; }
+		let sum = self.edges().fold(Vec2::zero(), |sum, (a, b)| sum + (a + b) * perp_dot(a, b));
+		Some(sum / (twice_area * (T::one() + T::two())))
+	}
+	
+	//by winding number, so self intersecting polygons count overlapping loops as inside
+	//points on an edge count as inside
+	pub fn contains_point(&self, p: Vec2<T>) -> bool
+		where T: Copy + PartialOrd + Orient2d {
+		let mut winding = 0i64;
+		for (a, b) in self.edges() {
+			let side = orient2d(a, b, p);
+			if side == Orientation::Collinear && in_box(a, b, p) { return true; }
+			if a.y <= p.y {
+				if b.y > p.y && side == Orientation::CounterClockwise { winding += 1; }
+			} else if b.y <= p.y && side == Orientation::Clockwise {
+				winding -= 1;
+			}
+		}
+		winding != 0
+	}
+	
+	//Andrew's monotone chain, counter clockwise starting from the lowest x (then y) point, without collinear points
+	//points that can't be ordered (nan) are dropped
+	pub fn convex_hull(points: &[Vec2<T>]) -> Self
+		where T: Copy + PartialOrd + Orient2d {
+		let mut sorted: Vec<Vec2<T>> = points.iter().copied().filter(|p| !p.x.is_nan() && !p.y.is_nan()).collect();
+		sorted.sort_by(|a, b| lexical(*a, *b));
+		sorted.dedup();
+		if sorted.len() < 3 { return polygon2(sorted); }
+		let mut hull: Vec<Vec2<T>> = Vec::with_capacity(sorted.len() * 2);
+		for pass in 0..2 {
+			let start = hull.len();
+			for &p in sorted.iter() {
+				while hull.len() >= start + 2 && orient2d(hull[hull.len() - 2], hull[hull.len() - 1], p) != Orientation::CounterClockwise {
+					hull.pop();
+				}
+				hull.push(p);
+			}
+			//each chain's last point is the next chain's first
+			hull.pop();
+			if pass == 0 { sorted.reverse(); }
+		}
+		polygon2(hull)
+	}
+}
+
+fn lexical<T: PartialOrd>(a: Vec2<T>, b: Vec2<T>) -> Ordering {
+	a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal).then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
+
+//p is within the bounding box of a and b, so if it is collinear with them it's on the segment
+fn in_box<T: PartialOrd>(a: Vec2<T>, b: Vec2<T>, p: Vec2<T>) -> bool {
+	let between = |a: T, b: T, p: T| (a <= p && p <= b) || (b <= p && p <= a);
+	between(a.x, b.x, p.x) && between(a.y, b.y, p.y)
+}
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Segment2<T> {
+	pub a: Vec2<T>,
+	pub b: Vec2<T>,
+}
+
+pub fn segment2<T>(a: Vec2<T>, b: Vec2<T>) -> Segment2<T> {
+	Segment2{ a, b }
+}
+
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum SegmentIntersection<T> {
+	None,
+	Point(Vec2<T>),
+	//collinear segments sharing more than a point, the shared part from one end to the other
+	Overlap(Vec2<T>, Vec2<T>),
+}
+
+impl<T> Segment2<T> {
+	//exact, endpoints touching counts
+	pub fn intersects(self, other: Self) -> bool
+		where T: Copy + PartialOrd + Orient2d {
+		let Segment2{ a,b } = self;
+		let Segment2{ a: c, b: d } = other;
+		let (o1, o2) = (orient2d(a, b, c), orient2d(a, b, d));
+		let (o3, o4) = (orient2d(c, d, a), orient2d(c, d, b));
+		if o1 != o2 && o3 != o4 && o1 != Orientation::Collinear && o3 != Orientation::Collinear {
+			return true;
+		}
+		(o1 == Orientation::Collinear && in_box(a, b, c))
+			|| (o2 == Orientation::Collinear && in_box(a, b, d))
+			|| (o3 == Orientation::Collinear && in_box(c, d, a))
+			|| (o4 == Orientation::Collinear && in_box(c, d, b))
+	}
+	
+	//whether they meet is decided exactly, the crossing point itself is rounded (for integers its offset from self.a is truncated toward zero)
+	pub fn intersection(self, other: Self) -> SegmentIntersection<T>
+		where T: Copy + PartialOrd + Zero + Orient2d + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		if !self.intersects(other) { return SegmentIntersection::None; }
+		let (r, s) = (self.b - self.a, other.b - other.a);
+		let denom = perp_dot(r, s);
+		if denom != T::zero() {
+			//multiplied before dividing so integers don't truncate the parameter to 0 or 1
+			return SegmentIntersection::Point(self.a + r * perp_dot(other.a - self.a, s) / denom);
+		}
+		//collinear (or one of them is a single point): the overlap is between the inner two of the four ends
+		let order = |a: Vec2<T>, b: Vec2<T>| if lexical(a, b) == Ordering::Greater { (b, a) } else { (a, b) };
+		let ((a0, a1), (b0, b1)) = (order(self.a, self.b), order(other.a, other.b));
+		let start = if lexical(a0, b0) == Ordering::Greater { a0 } else { b0 };
+		let end = if lexical(a1, b1) == Ordering::Less { a1 } else { b1 };
+		if start == end { SegmentIntersection::Point(start) } else { SegmentIntersection::Overlap(start, end) }
+	}
+}
@@ -11,3 +11,14 @@ pub const BRACKETS: &[char] = &['(', ')', '[', ']', '{', '}'];
 
 pub fn dot<T, V: Vector<T>>(a: V, b: V) -> T { a.dot(b) }
 pub fn distance<T, V: Vector<T>>(a: V, b: V) -> T { a.distance(b) }
+pub fn abs<T: PartialOrd + Zero + Neg<Output=T>>(a: T) -> T { if a < T::zero() { -a } else { a } }
+
+//lays out formatted matrix cells as right aligned rows, one per line
+pub fn fmt_grid(rows: Vec<Vec<String>>) -> String {
+	let cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+	let widths: Vec<usize> = (0..cols).map(|c| rows.iter().filter_map(|r| r.get(c)).map(|s| s.chars().count()).max().unwrap_or(0)).collect();
+	rows.iter().map(|r| {
+		let cells: Vec<String> = r.iter().zip(widths.iter()).map(|(s, &w)| format!("{:>1$}", s, w)).collect();
+		format!("({})", cells.join(", "))
+	}).collect::<Vec<String>>().join("\n")
+}
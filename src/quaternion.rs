@@ -2,6 +2,7 @@ use crate::prelude::*;
 
 use crate::mat3::*;
 use crate::vec3::*;
+use crate::angle::*;
 
 #[repr(C)]
 #[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
@@ -15,21 +16,253 @@ pub struct Quaternion<T> {
 impl<T> Quaternion<T> {
 	pub fn normalise(self) -> Self
 		where T: Copy + Sqrt<T> + Div<Output=T> + Mul<Output=T> + Add<Output=T> {
-		let magnitude = (self.i * self.i + self.j * self.j + self.k * self.k + self.r * self.r).sqrt();
+		let magnitude = self.norm();
 		Self { r: self.r / magnitude, i: self.i / magnitude, j: self.j / magnitude, k: self.k / magnitude }
 	}
 	
-	pub fn inverse(self) -> Self
+	pub fn conjugate(self) -> Self
 		where T: Neg<Output=T> {
-		Self { r: -self.r, i: self.i, j: self.j, k: self.k }
+		Self { r: self.r, i: -self.i, j: -self.j, k: -self.k }
+	}
+	
+	pub fn inverse(self) -> Self
+		where T: Copy + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		self.conjugate() / self.norm_squared()
+	}
+	
+	pub fn dot(self, other: Self) -> T
+		where T: Mul<Output=T> + Add<Output=T> {
+		self.r * other.r + self.i * other.i + self.j * other.j + self.k * other.k
+	}
+	
+	pub fn norm_squared(self) -> T
+		where T: Copy + Mul<Output=T> + Add<Output=T> {
+		self.dot(self)
+	}
+	
+	pub fn norm(self) -> T
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> {
+		self.norm_squared().sqrt()
+	}
+	
+	pub fn vector(self) -> Vec3<T> {
+		vec3(self.i, self.j, self.k)
 	}
 	
-	pub fn from_euler_angles(v: Vec3<T>) -> Self
-		where T: Copy + Sqrt<T> + Trig + Two + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
-		let magnitude = v.magnitude();
-		let (factor, r) = (magnitude / T::two()).sin_cos();
-		let (i, j, k) = (factor * v.x, factor * v.y, factor * v.z);
-		Self { r: r, i: i, j: j, k: k }
+	//assumes a unit quaternion, equivalent to self * (0, v) * self.conjugate()
+	pub fn rotate(self, v: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		let u = self.vector();
+		let t = cross(u, v) * T::two();
+		v + t * self.r + cross(u, t)
+	}
+	
+	#[deprecated(note = "takes a rotation vector rather than euler angles, use from_rotation_vector or from_euler")]
+	pub fn from_euler_angles(v: Vec3<Rad<T>>) -> Self
+		where T: Copy + PartialEq + Zero + One + Two + Sqrt<T> + Trig + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Self::from_rotation_vector(vec3(v.x.0, v.y.0, v.z.0))
+	}
+	
+	pub fn from_axis_angle(axis: Vec3<T>, angle: impl Into<Rad<T>>) -> Self
+		where T: Copy + Two + Sqrt<T> + Trig + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Rad(angle) = angle.into();
+		let (s, c) = (angle / T::two()).sin_cos();
+		let v = axis.normalize() * s;
+		Self { r: c, i: v.x, j: v.y, k: v.z }
+	}
+	
+	//v is the rotation axis scaled by the rotation angle
+	pub fn from_rotation_vector(v: Vec3<T>) -> Self
+		where T: Copy + PartialEq + Zero + One + Two + Sqrt<T> + Trig + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let angle = v.magnitude();
+		if angle == T::zero() { return Self::ident(); }
+		let (s, c) = (angle / T::two()).sin_cos();
+		let v = v * (s / angle);
+		Self { r: c, i: v.x, j: v.y, k: v.z }
+	}
+	
+	//assumes a unit quaternion, the returned angle is in [0, pi]
+	pub fn to_rotation_vector(self) -> Vec3<T>
+		where T: Copy + PartialOrd + Zero + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let q = if self.r < T::zero() { -self } else { self };
+		let v = q.vector();
+		let s = v.magnitude();
+		if s == T::zero() { return Vec3::zero(); }
+		v * (T::two() * s.atan2(q.r) / s)
+	}
+	
+	//first is applied about the first axis of `order`, second about the second and third about the third,
+	//each about the axes as rotated by the previous ones (so XYZ is Rx * Ry * Rz)
+	pub fn from_euler(order: EulerOrder, first: impl Into<Rad<T>>, second: impl Into<Rad<T>>, third: impl Into<Rad<T>>) -> Self
+		where T: Copy + Zero + One + Two + Trig + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let (a, b, c) = order.axes();
+		let axis = |n: usize, Rad(angle): Rad<T>| {
+			let (s, c) = (angle / T::two()).sin_cos();
+			let mut v = Vec3::zero();
+			v[n] = s;
+			Self { r: c, i: v.x, j: v.y, k: v.z }
+		};
+		axis(a, first.into()) * axis(b, second.into()) * axis(c, third.into())
+	}
+	
+	//inverse of from_euler for a unit quaternion, the first and last angles are in (-pi, pi]
+	//and the middle one is in [-pi/2, pi/2] for tait-bryan orders or [0, pi] for proper euler orders
+	pub fn to_euler(self, order: EulerOrder) -> (Rad<T>, Rad<T>, Rad<T>)
+		where T: Copy + PartialOrd + Pi + Two + Sqrt<T> + Trig + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		//Bernardes & Viollet 2022, on the equivalent extrinsic sequence (the intrinsic one reversed)
+		let (k, j, i) = order.axes();
+		let symmetric = i == k;
+		let k = if symmetric { 3 - i - j } else { k };
+		let odd = (i + 1) % 3 != j;
+		let (w, q) = (self.r, self.vector());
+		let qk = if odd { -q[k] } else { q[k] };
+		let (a, b, c, d) = if symmetric {
+			(w, q[i], q[j], qk)
+		} else {
+			(w - q[j], q[i] + qk, q[j] + w, qk - q[i])
+		};
+		let hypot = |x: T, y: T| (x * x + y * y).sqrt();
+		let wrap = |x: T| if x > T::pi() {
+			x - T::two() * T::pi()
+		} else if x <= -T::pi() {
+			x + T::two() * T::pi()
+		} else {
+			x
+		};
+		let mut middle = T::two() * hypot(c, d).atan2(hypot(a, b));
+		let (half_sum, half_diff) = (b.atan2(a), d.atan2(c));
+		let first = half_sum - half_diff;
+		let mut last = half_sum + half_diff;
+		if !symmetric {
+			if odd { last = -last; }
+			middle = middle - T::pi() / T::two();
+		}
+		(Rad(wrap(last)), Rad(middle), Rad(wrap(first)))
+	}
+	
+	//inverse of rot_mat for a rotation matrix, using Shepperd's method
+	pub fn from_rot_mat(m: Mat3<T>) -> Self
+		where T: Copy + PartialOrd + One + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Mat3{ x,y,z } = m;
+		let four = T::two() * T::two();
+		let trace = x.x + y.y + z.z;
+		if trace >= x.x && trace >= y.y && trace >= z.z {
+			let s = (T::one() + trace).sqrt() * T::two();
+			quaternion(s / four, (z.y - y.z) / s, (x.z - z.x) / s, (y.x - x.y) / s)
+		} else if x.x >= y.y && x.x >= z.z {
+			let s = (T::one() + x.x - y.y - z.z).sqrt() * T::two();
+			quaternion((z.y - y.z) / s, s / four, (x.y + y.x) / s, (x.z + z.x) / s)
+		} else if y.y >= z.z {
+			let s = (T::one() + y.y - x.x - z.z).sqrt() * T::two();
+			quaternion((x.z - z.x) / s, (x.y + y.x) / s, s / four, (y.z + z.y) / s)
+		} else {
+			let s = (T::one() + z.z - x.x - y.y).sqrt() * T::two();
+			quaternion((y.x - x.y) / s, (x.z + z.x) / s, (y.z + z.y) / s, s / four)
+		}
+	}
+	
+	//shortest rotation taking the direction of `from` to the direction of `to`
+	pub fn from_rotation_arc(from: Vec3<T>, to: Vec3<T>) -> Self
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (a, b) = (from.normalize(), to.normalize());
+		let d = dot(a, b);
+		let c = cross(a, b);
+		let r = T::one() + d;
+		if r <= T::zero() || (d < T::zero() && c == Vec3::zero()) {
+			//antiparallel, rotate half a turn about any perpendicular axis
+			let (x, y, z) = (a.x * a.x, a.y * a.y, a.z * a.z);
+			let e = if x <= y && x <= z {
+				vec3(T::one(), T::zero(), T::zero())
+			} else if y <= z {
+				vec3(T::zero(), T::one(), T::zero())
+			} else {
+				vec3(T::zero(), T::zero(), T::one())
+			};
+			let v = cross(a, e).normalize();
+			return quaternion(T::zero(), v.x, v.y, v.z);
+		}
+		quaternion(r, c.x, c.y, c.z).normalise()
+	}
+	
+	//normalised linear interpolation along the shorter arc
+	pub fn nlerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let other = if self.dot(other) < T::zero() { -other } else { other };
+		(self * (T::one() - t) + other * t).normalise()
+	}
+	
+	//spherical linear interpolation along the shorter arc, both quaternions should be unit length
+	pub fn slerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let other = if self.dot(other) < T::zero() { -other } else { other };
+		self.slerp_long(other, t)
+	}
+	
+	//spherical quadrangle interpolation between self and other, with a and b the control points
+	//for self and other as given by squad_control
+	pub fn squad(self, a: Self, b: Self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let outer = self.slerp_long(other, t);
+		let inner = a.slerp_long(b, t);
+		outer.slerp_long(inner, T::two() * t * (T::one() - t))
+	}
+	
+	//control point for squad at `current`, given its neighbouring keyframes
+	pub fn squad_control(prev: Self, current: Self, next: Self) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Exp + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let prev = if current.dot(prev) < T::zero() { -prev } else { prev };
+		let next = if current.dot(next) < T::zero() { -next } else { next };
+		let inv = current.conjugate();
+		let four = T::two() * T::two();
+		current * (((inv * prev).ln() + (inv * next).ln()) / -four).exp()
+	}
+	
+	//slerp without the shortest path flip, squad needs the intermediate curves to follow its control points
+	fn slerp_long(self, other: Self, t: T) -> Self
+		where T: Copy + PartialEq + Zero + One + Two + Sqrt<T> + Trig + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		//atan2 of the chord lengths stays accurate when the quaternions are nearly parallel, unlike acos of the dot product
+		let angle = T::two() * (self - other).norm().atan2((self + other).norm());
+		let s = angle.sin();
+		if s == T::zero() {
+			return (self * (T::one() - t) + other * t).normalise();
+		}
+		(self * ((T::one() - t) * angle).sin() + other * (t * angle).sin()) / s
+	}
+	
+	pub fn exp(self) -> Self
+		where T: Copy + PartialEq + Zero + Sqrt<T> + Trig + Exp + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let v = self.vector();
+		let angle = v.magnitude();
+		let scale = self.r.exp();
+		if angle == T::zero() {
+			return quaternion(scale, T::zero(), T::zero(), T::zero());
+		}
+		let (s, c) = angle.sin_cos();
+		let v = v * (scale * s / angle);
+		quaternion(scale * c, v.x, v.y, v.z)
+	}
+	
+	pub fn ln(self) -> Self
+		where T: Copy + PartialEq + Zero + Sqrt<T> + Trig + Exp + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let v = self.vector();
+		let s = v.magnitude();
+		let r = self.norm().ln();
+		if s == T::zero() {
+			return quaternion(r, T::zero(), T::zero(), T::zero());
+		}
+		let v = v * (s.atan2(self.r) / s);
+		quaternion(r, v.x, v.y, v.z)
+	}
+	
+	pub fn pow(self, n: T) -> Self
+		where T: Copy + PartialEq + Zero + Sqrt<T> + Trig + Exp + Add<Output=T> + Mul<Output=T> + Div<Output=T> {
+		(self.ln() * n).exp()
+	}
+	
+	//advances an orientation by a world space angular velocity (in radians per unit time) over dt
+	pub fn integrate(self, angular_velocity: Vec3<T>, dt: T) -> Self
+		where T: Copy + PartialEq + Zero + One + Two + Sqrt<T> + Trig + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		(Self::from_rotation_vector(angular_velocity * dt) * self).normalise()
 	}
 	
 	pub fn convert<U>(self) -> Quaternion<U>
@@ -59,6 +292,33 @@ impl<T: Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Copy + Zero + One> Produ
 	}
 }
 
+//axis sequence for euler angles, the first six are tait-bryan orders and the rest are proper euler orders
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub enum EulerOrder {
+	XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+	XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+impl EulerOrder {
+	pub fn axes(self) -> (usize, usize, usize) {
+		use EulerOrder::*;
+		match self {
+			XYZ => (0, 1, 2),
+			XZY => (0, 2, 1),
+			YXZ => (1, 0, 2),
+			YZX => (1, 2, 0),
+			ZXY => (2, 0, 1),
+			ZYX => (2, 1, 0),
+			XYX => (0, 1, 0),
+			XZX => (0, 2, 0),
+			YXY => (1, 0, 1),
+			YZY => (1, 2, 1),
+			ZXZ => (2, 0, 2),
+			ZYZ => (2, 1, 2),
+		}
+	}
+}
+
 pub fn quaternion<T>(r: T, i: T, j: T, k: T) -> Quaternion<T> {
 	Quaternion { r, i, j, k }
 }
@@ -78,6 +338,61 @@ impl<T> Mul<Quaternion<T>> for Quaternion<T>
 	}
 }
 
+impl<T> Mul<T> for Quaternion<T>
+	where T: Copy + Mul<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, scalar: T) -> Self {
+		quaternion(self.r * scalar, self.i * scalar, self.j * scalar, self.k * scalar)
+	}
+}
+
+impl<T> Div<T> for Quaternion<T>
+	where T: Copy + Div<Output=T>
+{
+	type Output = Self;
+	
+	fn div(self, scalar: T) -> Self {
+		quaternion(self.r / scalar, self.i / scalar, self.j / scalar, self.k / scalar)
+	}
+}
+
+impl<T> Add<Quaternion<T>> for Quaternion<T>
+	where T: Add<Output=T>
+{
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		quaternion(self.r + other.r, self.i + other.i, self.j + other.j, self.k + other.k)
+	}
+}
+
+impl<T> Sub<Quaternion<T>> for Quaternion<T>
+	where T: Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn sub(self, other: Self) -> Self {
+		quaternion(self.r - other.r, self.i - other.i, self.j - other.j, self.k - other.k)
+	}
+}
+
+impl<T> Mul<Vec3<T>> for Quaternion<T>
+	where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Vec3<T>;
+	
+	fn mul(self, v: Vec3<T>) -> Vec3<T> {
+		self.rotate(v)
+	}
+}
+
+impl<T: Neg> Neg for Quaternion<T> {
+	type Output = Quaternion<<T as Neg>::Output>;
+	fn neg(self) -> Quaternion<<T as Neg>::Output> { quaternion(-self.r,-self.i,-self.j,-self.k) }
+}
+
 impl<T> ArrayTuple for Quaternion<T> {
 	type Array = [T; 4];
 	type Tuple = (T,T,T,T);
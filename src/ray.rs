@@ -0,0 +1,120 @@
+use crate::prelude::*;
+
+use crate::vec3::*;
+use crate::aabb::*;
+use crate::shapes::*;
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Ray3<T> {
+	pub origin: Vec3<T>,
+	pub direction: Vec3<T>,
+}
+
+//distance is the ray parameter of the hit (so it's in units of the direction's length, the true distance if that is unit length)
+//normal is unit length and always faces back against the ray, for a ray starting inside a solid it is where the ray leaves
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct RayHit<T> {
+	pub distance: T,
+	pub normal: Vec3<T>,
+}
+
+pub fn ray3<T>(origin: Vec3<T>, direction: Vec3<T>) -> Ray3<T> {
+	Ray3{ origin, direction }
+}
+
+//every query returns None for a zero length direction and for nan anywhere in the inputs
+impl<T> Ray3<T> {
+	pub fn at(self, t: T) -> Vec3<T>
+		where T: Copy + Mul<Output=T> + Add<Output=T> {
+		self.origin + self.direction * t
+	}
+	
+	pub fn intersect_sphere(self, s: Sphere<T>) -> Option<RayHit<T>>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let a = dot(self.direction, self.direction);
+		if !(a > T::zero() && s.radius > T::zero()) { return None; }
+		let oc = self.origin - s.center;
+		let b = dot(oc, self.direction);
+		let c = dot(oc, oc) - s.radius * s.radius;
+		let disc = b * b - a * c;
+		if disc < T::zero() || disc.is_nan() { return None; }
+		let root = disc.sqrt();
+		let (near, far) = ((-b - root) / a, (-b + root) / a);
+		if far < T::zero() || far.is_nan() { return None; }
+		//starting inside, the sphere is left at the far root
+		let (t, sign) = if near >= T::zero() { (near, s.radius) } else { (far, -s.radius) };
+		Some(RayHit{ distance: t, normal: (self.at(t) - s.center) / sign })
+	}
+	
+	//a ray lying in the plane doesn't count as a hit
+	pub fn intersect_plane(self, p: Plane<T>) -> Option<RayHit<T>>
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let denom = dot(p.normal, self.direction);
+		if denom == T::zero() { return None; }
+		let t = -(dot(p.normal, self.origin) + p.d) / denom;
+		if t < T::zero() || t.is_nan() { return None; }
+		let normal = if denom < T::zero() { p.normal } else { -p.normal };
+		Some(RayHit{ distance: t, normal })
+	}
+	
+	//slab method, an axis the ray runs parallel to only has to have the origin between its two faces
+	pub fn intersect_aabb(self, b: Aabb3<T>) -> Option<RayHit<T>>
+		where T: Copy + PartialOrd + Zero + One + Neg<Output=T> + Sub<Output=T> + Div<Output=T> {
+		if self.direction == Vec3::zero() || b.is_empty() { return None; }
+		let (o, d) = (self.origin.into_array(), self.direction.into_array());
+		let (lo, hi) = (b.min.into_array(), b.max.into_array());
+		//the latest entry and earliest exit over the slabs, with the axis they happen on
+		let mut near: Option<(T, usize)> = None;
+		let mut far: Option<(T, usize)> = None;
+		for i in 0..3 {
+			if d[i] == T::zero() {
+				if !(lo[i] <= o[i] && o[i] <= hi[i]) { return None; }
+				continue;
+			}
+			let (enter, exit) = ((lo[i] - o[i]) / d[i], (hi[i] - o[i]) / d[i]);
+			let (enter, exit) = if exit < enter { (exit, enter) } else { (enter, exit) };
+			if enter.is_nan() || exit.is_nan() { return None; }
+			if near.is_none_or(|(n, _)| enter > n) { near = Some((enter, i)); }
+			if far.is_none_or(|(f, _)| exit < f) { far = Some((exit, i)); }
+		}
+		let ((near, near_axis), (far, far_axis)) = (near?, far?);
+		if !(near <= far && far >= T::zero()) { return None; }
+		let (t, axis) = if near >= T::zero() { (near, near_axis) } else { (far, far_axis) };
+		let mut normal = [T::zero(), T::zero(), T::zero()];
+		normal[axis] = if d[axis] > T::zero() { -T::one() } else { T::one() };
+		let [x, y, z] = normal;
+		Some(RayHit{ distance: t, normal: vec3(x, y, z) })
+	}
+	
+	//Möller–Trumbore, both sides of the triangle are hit, degenerate (zero area) triangles are never hit
+	pub fn intersect_triangle(self, tri: Triangle3<T>) -> Option<RayHit<T>>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (e1, e2) = (tri.b - tri.a, tri.c - tri.a);
+		let p = cross(self.direction, e2);
+		let det = dot(e1, p);
+		if det == T::zero() { return None; }
+		let inv = T::one() / det;
+		let s = self.origin - tri.a;
+		let u = dot(s, p) * inv;
+		if !(u >= T::zero() && u <= T::one()) { return None; }
+		let q = cross(s, e1);
+		let v = dot(self.direction, q) * inv;
+		if !(v >= T::zero() && u + v <= T::one()) { return None; }
+		let t = dot(e2, q) * inv;
+		if t < T::zero() || t.is_nan() { return None; }
+		let n = cross(e1, e2).normalize();
+		let normal = if dot(n, self.direction) > T::zero() { -n } else { n };
+		Some(RayHit{ distance: t, normal })
+	}
+	
+	//done as a ray against an aabb in the box's own space
+	pub fn intersect_obb(self, b: Obb3<T>) -> Option<RayHit<T>>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let to_local = b.axes.transpose();
+		let local = ray3(to_local.apply_to(self.origin - b.center), to_local.apply_to(self.direction));
+		let hit = local.intersect_aabb(aabb3(-b.half_extents, b.half_extents))?;
+		Some(RayHit{ distance: hit.distance, normal: b.axes.apply_to(hit.normal) })
+	}
+}
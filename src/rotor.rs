@@ -0,0 +1,262 @@
+use crate::prelude::*;
+
+use crate::vec3::*;
+use crate::mat3::*;
+use crate::quaternion::*;
+use crate::angle::*;
+
+//an oriented plane segment, the coefficients of e1e2, e1e3 and e2e3
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Bivec3<T> {
+	pub xy: T,
+	pub xz: T,
+	pub yz: T,
+}
+
+pub fn bivec3<T>(xy: T, xz: T, yz: T) -> Bivec3<T> {
+	Bivec3 { xy, xz, yz }
+}
+
+//the plane spanned by a and b, oriented from a towards b, with the area of their parallelogram
+pub fn wedge<T>(a: Vec3<T>, b: Vec3<T>) -> Bivec3<T>
+	where T: Copy + Mul<Output=T> + Sub<Output=T> {
+	bivec3(a.x * b.y - a.y * b.x, a.x * b.z - a.z * b.x, a.y * b.z - a.z * b.y)
+}
+
+//ab = a.b + a^b, a scalar plus a bivector, which is a rotor (unnormalised) twice the angle from b to a
+pub fn geometric_product<T>(a: Vec3<T>, b: Vec3<T>) -> Rotor3<T>
+	where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+	rotor3(dot(a, b), wedge(a, b))
+}
+
+impl<T> Bivec3<T> {
+	pub fn zero() -> Self
+		where T: Zero {
+		bivec3(T::zero(), T::zero(), T::zero())
+	}
+	
+	pub fn magnitude_squared(self) -> T
+		where T: Copy + Mul<Output=T> + Add<Output=T> {
+		self.xy * self.xy + self.xz * self.xz + self.yz * self.yz
+	}
+	
+	pub fn magnitude(self) -> T
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> {
+		self.magnitude_squared().sqrt()
+	}
+	
+	pub fn normalize(self) -> Self
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		self / self.magnitude()
+	}
+	
+	//the normal of the plane, wedge(a, b).to_vec3() == cross(a, b)
+	pub fn to_vec3(self) -> Vec3<T>
+		where T: Neg<Output=T> {
+		vec3(self.yz, -self.xz, self.xy)
+	}
+	
+	//the plane with v as its normal
+	pub fn from_vec3(v: Vec3<T>) -> Self
+		where T: Neg<Output=T> {
+		bivec3(v.z, -v.y, v.x)
+	}
+	
+	//e^B = cos|B| + B/|B| sin|B|, the rotor rotating by twice |B| against the orientation of B
+	pub fn exp(self) -> Rotor3<T>
+		where T: Copy + PartialEq + Zero + One + Sqrt<T> + Trig + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		let angle = self.magnitude();
+		if angle == T::zero() { return Rotor3::ident(); }
+		let (s, c) = angle.sin_cos();
+		rotor3(c, self * (s / angle))
+	}
+}
+
+impl<T> Add<Bivec3<T>> for Bivec3<T>
+	where T: Add<Output=T>
+{
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		bivec3(self.xy + other.xy, self.xz + other.xz, self.yz + other.yz)
+	}
+}
+
+impl<T> Sub<Bivec3<T>> for Bivec3<T>
+	where T: Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn sub(self, other: Self) -> Self {
+		bivec3(self.xy - other.xy, self.xz - other.xz, self.yz - other.yz)
+	}
+}
+
+impl<T> Mul<T> for Bivec3<T>
+	where T: Copy + Mul<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, scalar: T) -> Self {
+		bivec3(self.xy * scalar, self.xz * scalar, self.yz * scalar)
+	}
+}
+
+impl<T> Div<T> for Bivec3<T>
+	where T: Copy + Div<Output=T>
+{
+	type Output = Self;
+	
+	fn div(self, scalar: T) -> Self {
+		bivec3(self.xy / scalar, self.xz / scalar, self.yz / scalar)
+	}
+}
+
+impl<T: Neg> Neg for Bivec3<T> {
+	type Output = Bivec3<<T as Neg>::Output>;
+	fn neg(self) -> Bivec3<<T as Neg>::Output> { bivec3(-self.xy, -self.xz, -self.yz) }
+}
+
+//a scalar plus a bivector, the even part of the 3d geometric algebra
+//a unit one rotates v as R v R~, R = e^(-B θ/2) turns by θ in the plane of the unit bivector B
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Rotor3<T> {
+	pub s: T,
+	pub b: Bivec3<T>,
+}
+
+pub fn rotor3<T>(s: T, b: Bivec3<T>) -> Rotor3<T> {
+	Rotor3 { s, b }
+}
+
+impl<T> Rotor3<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		rotor3(T::one(), Bivec3::zero())
+	}
+	
+	//turns by angle in the plane, from the first vector of the wedge towards the second
+	pub fn from_plane_angle(plane: Bivec3<T>, angle: impl Into<Rad<T>>) -> Self
+		where T: Copy + PartialEq + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		let Rad(angle) = angle.into();
+		(-plane.normalize() * (angle / T::two())).exp()
+	}
+	
+	//the shortest rotation taking the direction of from to the direction of to, the normalised 1 + to from
+	pub fn from_rotation_arc(from: Vec3<T>, to: Vec3<T>) -> Self
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (a, b) = (from.normalize(), to.normalize());
+		let d = dot(a, b);
+		let w = wedge(b, a);
+		let r = T::one() + d;
+		if r <= T::zero() || (d < T::zero() && w == Bivec3::zero()) {
+			//antiparallel, half a turn in any plane containing a
+			return rotor3(T::zero(), wedge(any_perpendicular(a), a).normalize());
+		}
+		rotor3(r, w).normalise()
+	}
+	
+	//the reverse, which undoes a unit rotor
+	pub fn reverse(self) -> Self
+		where T: Neg<Output=T> {
+		rotor3(self.s, -self.b)
+	}
+	
+	pub fn norm_squared(self) -> T
+		where T: Copy + Mul<Output=T> + Add<Output=T> {
+		self.s * self.s + self.b.magnitude_squared()
+	}
+	
+	pub fn norm(self) -> T
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> {
+		self.norm_squared().sqrt()
+	}
+	
+	pub fn normalise(self) -> Self
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		let n = self.norm();
+		rotor3(self.s / n, self.b / n)
+	}
+	
+	//the bivector whose exp is self, for a unit rotor
+	pub fn ln(self) -> Bivec3<T>
+		where T: Copy + PartialEq + Zero + Sqrt<T> + Trig + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		let s = self.b.magnitude();
+		if s == T::zero() { return Bivec3::zero(); }
+		self.b * (s.atan2(self.s) / s)
+	}
+	
+	//interpolates the angle, for a unit rotor
+	pub fn pow(self, n: T) -> Self
+		where T: Copy + PartialEq + Zero + One + Sqrt<T> + Trig + Mul<Output=T> + Add<Output=T> + Div<Output=T> {
+		(self.ln() * n).exp()
+	}
+	
+	//R v R~ for a unit rotor
+	pub fn rotate(self, v: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.to_quaternion().rotate(v)
+	}
+	
+	pub fn rot_mat(self) -> Mat3<T>
+		where T: Copy + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.to_quaternion().rot_mat()
+	}
+	
+	//the even subalgebra is the quaternions, with i = -e2e3, j = -e3e1 and k = -e1e2
+	//so the conversions only move and negate components and lose nothing
+	pub fn to_quaternion(self) -> Quaternion<T>
+		where T: Neg<Output=T> {
+		let Rotor3{ s, b: Bivec3{ xy,xz,yz } } = self;
+		quaternion(s, -yz, xz, -xy)
+	}
+	
+	pub fn from_quaternion(q: Quaternion<T>) -> Self
+		where T: Neg<Output=T> {
+		let Quaternion{ r,i,j,k } = q;
+		rotor3(r, bivec3(-k, j, -i))
+	}
+}
+
+impl<T> Default for Rotor3<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+//the geometric product, self after other for unit rotors
+impl<T> Mul<Rotor3<T>> for Rotor3<T>
+	where T: Copy + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		Self::from_quaternion(self.to_quaternion() * other.to_quaternion())
+	}
+}
+
+impl<T> Mul<Vec3<T>> for Rotor3<T>
+	where T: Copy + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Vec3<T>;
+	
+	fn mul(self, v: Vec3<T>) -> Vec3<T> {
+		self.rotate(v)
+	}
+}
+
+impl<T: Neg<Output=T>> From<Quaternion<T>> for Rotor3<T> {
+	fn from(q: Quaternion<T>) -> Self {
+		Self::from_quaternion(q)
+	}
+}
+
+impl<T: Neg<Output=T>> From<Rotor3<T>> for Quaternion<T> {
+	fn from(r: Rotor3<T>) -> Self {
+		r.to_quaternion()
+	}
+}
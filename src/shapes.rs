@@ -0,0 +1,279 @@
+use crate::prelude::*;
+
+use crate::vec3::*;
+use crate::mat3::*;
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Sphere<T> {
+	pub center: Vec3<T>,
+	pub radius: T,
+}
+
+//the points p with dot(normal, p) + d = 0, normal is expected to be unit length
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Plane<T> {
+	pub normal: Vec3<T>,
+	pub d: T,
+}
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Triangle3<T> {
+	pub a: Vec3<T>,
+	pub b: Vec3<T>,
+	pub c: Vec3<T>,
+}
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Segment3<T> {
+	pub a: Vec3<T>,
+	pub b: Vec3<T>,
+}
+
+//every point within radius of the segment from a to b
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Capsule<T> {
+	pub a: Vec3<T>,
+	pub b: Vec3<T>,
+	pub radius: T,
+}
+
+//the columns of axes are the box's local x, y and z directions (so it rotates box space into world space)
+//and are expected to be orthonormal, half_extents is the distance from the center to each face along those axes
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Obb3<T> {
+	pub center: Vec3<T>,
+	pub axes: Mat3<T>,
+	pub half_extents: Vec3<T>,
+}
+
+pub fn sphere<T>(center: Vec3<T>, radius: T) -> Sphere<T> {
+	Sphere{ center, radius }
+}
+
+pub fn plane<T>(normal: Vec3<T>, d: T) -> Plane<T> {
+	Plane{ normal, d }
+}
+
+pub fn triangle3<T>(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Triangle3<T> {
+	Triangle3{ a, b, c }
+}
+
+pub fn segment3<T>(a: Vec3<T>, b: Vec3<T>) -> Segment3<T> {
+	Segment3{ a, b }
+}
+
+pub fn capsule<T>(a: Vec3<T>, b: Vec3<T>, radius: T) -> Capsule<T> {
+	Capsule{ a, b, radius }
+}
+
+pub fn obb3<T>(center: Vec3<T>, axes: Mat3<T>, half_extents: Vec3<T>) -> Obb3<T> {
+	Obb3{ center, axes, half_extents }
+}
+
+//spheres, capsules and boxes are solid: closest_point gives p itself for a point inside
+//and signed_distance is negative inside, distance_to_point is 0 there
+impl<T> Plane<T> {
+	//the normal follows the winding, counter clockwise seen from the side it points to
+	pub fn from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Self
+		where T: Copy + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		Plane::from_point_normal(a, cross(b - a, c - a).normalize())
+	}
+	
+	pub fn from_point_normal(p: Vec3<T>, normal: Vec3<T>) -> Self
+		where T: Copy + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		plane(normal, -dot(normal, p))
+	}
+	
+	//rescales a plane whose normal isn't unit length, the points on it stay the same
+	pub fn normalize(self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let len = self.normal.magnitude();
+		plane(self.normal / len, self.d / len)
+	}
+	
+	//positive on the side the normal points to
+	pub fn signed_distance(self, p: Vec3<T>) -> T
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		dot(self.normal, p) + self.d
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		abs(self.signed_distance(p))
+	}
+	
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		p - self.normal * self.signed_distance(p)
+	}
+}
+
+impl<T> Sphere<T> {
+	pub fn signed_distance(self, p: Vec3<T>) -> T
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		(p - self.center).magnitude() - self.radius
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self.signed_distance(p).non_nan_max(T::zero())
+	}
+	
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + PartialOrd + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let offset = p - self.center;
+		let len = offset.magnitude();
+		if len <= self.radius { p } else { self.center + offset * (self.radius / len) }
+	}
+}
+
+impl<T> Segment3<T> {
+	pub fn length(self) -> T
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		(self.b - self.a).magnitude()
+	}
+	
+	//how far along the segment the closest point to p is, 0 at a and 1 at b
+	pub fn closest_param(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let ab = self.b - self.a;
+		let len_sq = dot(ab, ab);
+		if len_sq == T::zero() { return T::zero(); }
+		(dot(p - self.a, ab) / len_sq).non_nan_max(T::zero()).non_nan_min(T::one())
+	}
+	
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self.a + (self.b - self.a) * self.closest_param(p)
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		(p - self.closest_point(p)).magnitude()
+	}
+}
+
+impl<T> Triangle3<T> {
+	//unit length, counter clockwise winding seen from the side it points to
+	pub fn normal(self) -> Vec3<T>
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		cross(self.b - self.a, self.c - self.a).normalize()
+	}
+	
+	pub fn area(self) -> T
+		where T: Copy + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		cross(self.b - self.a, self.c - self.a).magnitude() / T::two()
+	}
+	
+	//the weights of a, b and c (summing to 1) for p projected onto the triangle's plane
+	//they are all between 0 and 1 exactly when that projection is inside the triangle
+	pub fn barycentric(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let (v0, v1, v2) = (self.b - self.a, self.c - self.a, p - self.a);
+		let (d00, d01, d11) = (dot(v0, v0), dot(v0, v1), dot(v1, v1));
+		let (d20, d21) = (dot(v2, v0), dot(v2, v1));
+		let denom = d00 * d11 - d01 * d01;
+		let v = (d11 * d20 - d01 * d21) / denom;
+		let w = (d00 * d21 - d01 * d20) / denom;
+		vec3(T::one() - v - w, v, w)
+	}
+	
+	//picks the vertex, edge or face region p falls in (Ericson, real-time collision detection 5.1.5)
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Triangle3{ a,b,c } = self;
+		let zero = T::zero();
+		let (ab, ac, ap) = (b - a, c - a, p - a);
+		let (d1, d2) = (dot(ab, ap), dot(ac, ap));
+		if d1 <= zero && d2 <= zero { return a; }
+		let bp = p - b;
+		let (d3, d4) = (dot(ab, bp), dot(ac, bp));
+		if d3 >= zero && d4 <= d3 { return b; }
+		let vc = d1 * d4 - d3 * d2;
+		if vc <= zero && d1 >= zero && d3 <= zero { return a + ab * (d1 / (d1 - d3)); }
+		let cp = p - c;
+		let (d5, d6) = (dot(ab, cp), dot(ac, cp));
+		if d6 >= zero && d5 <= d6 { return c; }
+		let vb = d5 * d2 - d1 * d6;
+		if vb <= zero && d2 >= zero && d6 <= zero { return a + ac * (d2 / (d2 - d6)); }
+		let va = d3 * d6 - d5 * d4;
+		if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+			return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+		}
+		let denom = T::one() / (va + vb + vc);
+		a + ab * (vb * denom) + ac * (vc * denom)
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		(p - self.closest_point(p)).magnitude()
+	}
+	
+	//the distance, negative behind the triangle (on the side the normal points away from)
+	pub fn signed_distance(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let d = self.distance_to_point(p);
+		if dot(cross(self.b - self.a, self.c - self.a), p - self.a) < T::zero() { -d } else { d }
+	}
+}
+
+impl<T> Capsule<T> {
+	pub fn segment(self) -> Segment3<T> {
+		segment3(self.a, self.b)
+	}
+	
+	pub fn signed_distance(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self.segment().distance_to_point(p) - self.radius
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self.signed_distance(p).non_nan_max(T::zero())
+	}
+	
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let q = self.segment().closest_point(p);
+		let len = (p - q).magnitude();
+		if len <= self.radius { p } else { q + (p - q) * (self.radius / len) }
+	}
+}
+
+impl<T> Obb3<T> {
+	//p in the box's own space, where the box is centered on the origin and axis aligned
+	pub fn to_local(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self.axes.transpose().apply_to(p - self.center)
+	}
+	
+	pub fn from_local(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self.center + self.axes.apply_to(p)
+	}
+	
+	pub fn closest_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + PartialOrd + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self.from_local(self.to_local(p).clamp(-self.half_extents, self.half_extents))
+	}
+	
+	pub fn distance_to_point(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		(p - self.closest_point(p)).magnitude()
+	}
+	
+	//inside, minus the distance to the nearest face
+	pub fn signed_distance(self, p: Vec3<T>) -> T
+		where T: Copy + PartialOrd + Zero + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let local = self.to_local(p);
+		let Vec3{ x,y,z } = self.half_extents;
+		let depth = (x - abs(local.x)).non_nan_min(y - abs(local.y)).non_nan_min(z - abs(local.z));
+		if depth > T::zero() { -depth } else { self.distance_to_point(p) }
+	}
+}
@@ -24,6 +24,11 @@ pub trait Trig: Sized {
 	fn atanh(self) -> Self;
 }
 
+pub trait Exp: Sized {
+	fn exp(self) -> Self;
+	fn ln(self) -> Self;
+}
+
 pub trait IsNan: PartialOrd {
 	fn is_nan(&self) -> bool;
 	fn non_nan_max(self, other: Self) -> Self;
@@ -36,10 +41,40 @@ impl<T: PartialOrd + Sized> IsNan for T {
 	default fn non_nan_min(self, other: Self) -> Self { if other.is_nan() || self < other { self } else { other } }
 }
 
+//types that can be viewed as and rebuilt from raw bytes
+/// # Safety
+/// implementors must have no padding bytes and every bit pattern must be a valid value
+pub unsafe trait Pod: Copy + 'static {
+	fn as_bytes(&self) -> &[u8] {
+		unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, std::mem::size_of::<Self>()) }
+	}
+	
+	//None if the length doesn't match, alignment doesn't matter since the bytes are copied out
+	fn from_bytes(bytes: &[u8]) -> Option<Self> {
+		if bytes.len() == std::mem::size_of::<Self>() {
+			Some(unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const Self) })
+		} else {
+			None
+		}
+	}
+}
+
+macro pod_impl($($t: ty),*) {
+	$(unsafe impl Pod for $t {})*
+}
+
+pod_impl!(u8,u16,u32,u64,u128,usize,i8,i16,i32,i64,i128,isize,f32,f64);
+
 pub trait NiceFmt {
 	fn nice_fmt(&self, limit: usize, pad: bool) -> String;
 }
 
+impl<T: NiceFmt + ?Sized> NiceFmt for &T {
+	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+		(**self).nice_fmt(limit, pad)
+	}
+}
+
 macro float_impl($t: ty) {
 	impl Sqrt<Self> for $t {
 		fn sqrt(self) -> Self { self.sqrt() }
@@ -62,6 +97,11 @@ macro float_impl($t: ty) {
 		fn atanh(self) -> Self { self.atanh() }
 	}
 	
+	impl Exp for $t {
+		fn exp(self) -> Self { self.exp() }
+		fn ln(self) -> Self { self.ln() }
+	}
+	
 	impl IsNan for $t {
 		fn is_nan(&self) -> bool { <$t>::is_nan(*self) }
 		fn non_nan_max(self, other: Self) -> Self { self.max(other) }
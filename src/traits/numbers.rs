@@ -10,6 +10,15 @@ pub trait Two {
 	fn two() -> Self;
 }
 
+pub trait Pi {
+	fn pi() -> Self;
+}
+
+//the gap between 1 and the next representable value
+pub trait Epsilon {
+	fn epsilon() -> Self;
+}
+
 macro impl_ints($($t: ty),*) {
 	$(
 		impl Zero for $t {
@@ -39,8 +48,16 @@ macro impl_floats($($t: ident),*) {
 		impl Two for $t {
 			fn two() -> Self { 2.0 }
 		}
+		
+		impl Pi for $t {
+			fn pi() -> Self { std::$t::consts::PI }
+		}
+		
+		impl Epsilon for $t {
+			fn epsilon() -> Self { $t::EPSILON }
+		}
 	)*
 }
 
-impl_ints!(u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
+impl_ints!(u8,u16,u32,u64,u128,usize,i8,i16,i32,i64,i128,isize);
 impl_floats!(f32,f64);
@@ -0,0 +1,338 @@
+use crate::prelude::*;
+
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+use crate::mat2::*;
+use crate::mat3::*;
+use crate::mat4::*;
+use crate::quaternion::*;
+
+impl<T> Mat4<T> {
+	//translation * rotation * scale, so a point is scaled first and translated last
+	pub fn from_trs(translation: Vec3<T>, rotation: Quaternion<T>, scale: Vec3<T>) -> Self
+		where T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		let Mat3{ x,y,z } = rotation.rot_mat();
+		Mat3{ x: x * scale, y: y * scale, z: z * scale }.extend(translation, Vec3::zero(), T::one())
+	}
+	
+	//the inverse of from_trs, None for shear, perspective or a zero scale
+	//a mirroring matrix gives a negative x scale
+	pub fn decompose(self) -> Option<(Vec3<T>, Quaternion<T>, Vec3<T>)>
+		where T: Copy + PartialOrd + Zero + One + Two + Epsilon + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self.decompose_eps(T::epsilon().sqrt())
+	}
+	
+	//epsilon is relative: the bottom row is compared against (0, 0, 0, 1) and the cosines between the scaled axes against 0
+	pub fn decompose_eps(self, epsilon: T) -> Option<(Vec3<T>, Quaternion<T>, Vec3<T>)>
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Mat4{ x,y,z,w } = self;
+		let bottom = w - vec4(T::zero(), T::zero(), T::zero(), T::one());
+		//negated so nan fails too
+		if !(abs(bottom.x) <= epsilon && abs(bottom.y) <= epsilon && abs(bottom.z) <= epsilon && abs(bottom.w) <= epsilon) { return None; }
+		let translation = vec3(x.w, y.w, z.w);
+		let Mat3{ x: a, y: b, z: c } = mat3(x.downsize(), y.downsize(), z.downsize()).transpose();
+		let (sa, sb, sc) = (a.magnitude(), b.magnitude(), c.magnitude());
+		if !(sa > T::zero() && sb > T::zero() && sc > T::zero()) { return None; }
+		let skewed = |u: Vec3<T>, v: Vec3<T>, su: T, sv: T| {
+			let d = abs(dot(u, v));
+			d > epsilon * su * sv || d.is_nan()
+		};
+		if skewed(a, b, sa, sb) || skewed(a, c, sa, sc) || skewed(b, c, sb, sc) { return None; }
+		let sa = if dot(cross(a, b), c) < T::zero() { -sa } else { sa };
+		let rotation = mat3(a / sa, b / sb, c / sc).transpose();
+		Some((translation, Quaternion::from_rot_mat(rotation).normalise(), vec3(sa, sb, sc)))
+	}
+}
+
+//translation, rotation and scale, applied to a point in the order scale, rotation, translation
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Transform3<T> {
+	pub translation: Vec3<T>,
+	pub rotation: Quaternion<T>,
+	pub scale: Vec3<T>,
+}
+
+pub fn transform3<T>(translation: Vec3<T>, rotation: Quaternion<T>, scale: Vec3<T>) -> Transform3<T> {
+	Transform3{ translation, rotation, scale }
+}
+
+impl<T> Transform3<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		transform3(Vec3::zero(), Quaternion::ident(), Vec3::one())
+	}
+	
+	pub fn from_mat4(m: Mat4<T>) -> Option<Self>
+		where T: Copy + PartialOrd + Zero + One + Two + Epsilon + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		m.decompose().map(|(t, r, s)| transform3(t, r, s))
+	}
+	
+	pub fn to_mat4(self) -> Mat4<T>
+		where T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		Mat4::from_trs(self.translation, self.rotation, self.scale)
+	}
+	
+	pub fn transform_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.rotation.rotate(p * self.scale) + self.translation
+	}
+	
+	pub fn transform_vector(self, v: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.rotation.rotate(v * self.scale)
+	}
+	
+	//the transform applying other first and then self
+	//exact when self has a uniform scale, otherwise the shear a matrix product would give is dropped
+	pub fn compose(self, other: Self) -> Self
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		transform3(self.transform_point(other.translation), self.rotation * other.rotation, self.scale * other.scale)
+	}
+	
+	//exact for a uniform scale, like compose
+	pub fn inverse(self) -> Self
+		where T: Copy + One + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let rotation = self.rotation.conjugate();
+		let scale = Vec3::<T>::one() / self.scale;
+		transform3(rotation.rotate(-self.translation) * scale, rotation, scale)
+	}
+	
+	//translation and scale linearly, rotation by slerp along the shorter arc
+	pub fn lerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let mix = |a: Vec3<T>, b: Vec3<T>| a + (b - a) * t;
+		transform3(mix(self.translation, other.translation), self.rotation.slerp(other.rotation, t), mix(self.scale, other.scale))
+	}
+}
+
+impl<T> Default for Transform3<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+impl<T> Mul<Transform3<T>> for Transform3<T>
+	where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		self.compose(other)
+	}
+}
+
+//a linear map followed by a translation, the homogeneous matrix without its constant bottom row
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Affine2<T> {
+	pub matrix: Mat2<T>,
+	pub translation: Vec2<T>,
+}
+
+pub fn affine2<T>(matrix: Mat2<T>, translation: Vec2<T>) -> Affine2<T> {
+	Affine2{ matrix, translation }
+}
+
+impl<T> Affine2<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		affine2(Mat2::ident(), Vec2::zero())
+	}
+	
+	pub fn transform_point(self, p: Vec2<T>) -> Vec2<T>
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.matrix.apply_to(p) + self.translation
+	}
+	
+	pub fn transform_vector(self, v: Vec2<T>) -> Vec2<T>
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.matrix.apply_to(v)
+	}
+	
+	//only the 2x2 matrix is inverted, as with Mat2::inv a singular matrix gives infinities or nans
+	pub fn inverse(self) -> Self
+		where T: Copy + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let matrix = self.matrix.inv();
+		affine2(matrix, -matrix.apply_to(self.translation))
+	}
+	
+	//returns None when the determinant is exactly zero
+	pub fn try_inverse(self) -> Option<Self>
+		where T: Copy + PartialEq + Zero + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let matrix = self.matrix.try_inv()?;
+		Some(affine2(matrix, -matrix.apply_to(self.translation)))
+	}
+	
+	pub fn to_mat3(self) -> Mat3<T>
+		where T: Zero + One {
+		let Affine2{ matrix: Mat2{ x,y }, translation: t } = self;
+		mat3(vec3(x.x, x.y, t.x), vec3(y.x, y.y, t.y), vec3(T::zero(), T::zero(), T::one()))
+	}
+	
+	//the bottom row is ignored, so projective matrices lose their projection
+	pub fn from_mat3(m: Mat3<T>) -> Self {
+		let Mat3{ x,y,.. } = m;
+		affine2(mat2(vec2(x.x, x.y), vec2(y.x, y.y)), vec2(x.z, y.z))
+	}
+}
+
+impl<T> Default for Affine2<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+//self after other, like the product of their matrices
+impl<T> Mul<Affine2<T>> for Affine2<T>
+	where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		affine2(self.matrix * other.matrix, self.transform_point(other.translation))
+	}
+}
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Affine3<T> {
+	pub matrix: Mat3<T>,
+	pub translation: Vec3<T>,
+}
+
+pub fn affine3<T>(matrix: Mat3<T>, translation: Vec3<T>) -> Affine3<T> {
+	Affine3{ matrix, translation }
+}
+
+impl<T> Affine3<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		affine3(Mat3::ident(), Vec3::zero())
+	}
+	
+	pub fn transform_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.matrix.apply_to(p) + self.translation
+	}
+	
+	pub fn transform_vector(self, v: Vec3<T>) -> Vec3<T>
+		where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.matrix.apply_to(v)
+	}
+	
+	//only the 3x3 matrix is inverted, as with Mat3::inv a singular matrix gives infinities or nans
+	pub fn inverse(self) -> Self
+		where T: Copy + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let matrix = self.matrix.inv();
+		affine3(matrix, -matrix.apply_to(self.translation))
+	}
+	
+	//returns None when the determinant is exactly zero
+	pub fn try_inverse(self) -> Option<Self>
+		where T: Copy + PartialEq + Zero + Sqrt<T> + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+		let matrix = self.matrix.try_inv()?;
+		Some(affine3(matrix, -matrix.apply_to(self.translation)))
+	}
+	
+	pub fn to_mat4(self) -> Mat4<T>
+		where T: Zero + One {
+		self.matrix.extend(self.translation, Vec3::zero(), T::one())
+	}
+	
+	//the bottom row is ignored, so projective matrices lose their projection
+	pub fn from_mat4(m: Mat4<T>) -> Self
+		where T: Copy {
+		let Mat4{ x,y,z,.. } = m;
+		affine3(mat3(x.downsize(), y.downsize(), z.downsize()), vec3(x.w, y.w, z.w))
+	}
+}
+
+impl<T> Default for Affine3<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+//self after other, like the product of their matrices
+impl<T> Mul<Affine3<T>> for Affine3<T>
+	where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		affine3(self.matrix * other.matrix, self.transform_point(other.translation))
+	}
+}
+
+//a rotation followed by a translation, the rotation should be a unit quaternion
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
+pub struct Isometry3<T> {
+	pub rotation: Quaternion<T>,
+	pub translation: Vec3<T>,
+}
+
+pub fn isometry3<T>(rotation: Quaternion<T>, translation: Vec3<T>) -> Isometry3<T> {
+	Isometry3{ rotation, translation }
+}
+
+impl<T> Isometry3<T> {
+	pub fn ident() -> Self
+		where T: Zero + One {
+		isometry3(Quaternion::ident(), Vec3::zero())
+	}
+	
+	pub fn transform_point(self, p: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.rotation.rotate(p) + self.translation
+	}
+	
+	pub fn transform_vector(self, v: Vec3<T>) -> Vec3<T>
+		where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.rotation.rotate(v)
+	}
+	
+	//exact, the conjugate undoes a unit rotation
+	pub fn inverse(self) -> Self
+		where T: Copy + Two + Neg<Output=T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		let rotation = self.rotation.conjugate();
+		isometry3(rotation, -rotation.rotate(self.translation))
+	}
+	
+	pub fn to_affine3(self) -> Affine3<T>
+		where T: Copy + One + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		affine3(self.rotation.rot_mat(), self.translation)
+	}
+	
+	pub fn to_mat4(self) -> Mat4<T>
+		where T: Copy + Zero + One + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+		self.to_affine3().to_mat4()
+	}
+}
+
+impl<T> Default for Isometry3<T>
+	where T: Zero + One
+{
+	fn default() -> Self {
+		Self::ident()
+	}
+}
+
+//self after other
+impl<T> Mul<Isometry3<T>> for Isometry3<T>
+	where T: Copy + Two + Mul<Output=T> + Add<Output=T> + Sub<Output=T>
+{
+	type Output = Self;
+	
+	fn mul(self, other: Self) -> Self {
+		isometry3(self.rotation * other.rotation, self.transform_point(other.translation))
+	}
+}
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 use crate::vec3::*;
+use crate::angle::*;
 
 #[repr(C)]
 #[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
@@ -91,291 +92,104 @@ impl<T> Vec2<T> {
 	pub fn extend(self, z: T) -> Vec3<T> {
 		vec3(self.x, self.y, z)
 	}
-}
-
-pub fn angle_between<T>(a: Vec2<T>, b: Vec2<T>) -> T
-	where T: Copy + Trig + Sqrt<T> + Mul<Output=T> + Div<Output=T> + Add<Output=T> + Sub<Output=T> {
-	let (a, b) = (a.normalize(), b.normalize());
-	dot(a, b).acos()
-}
-
-pub use crate::prelude::{dot,distance};
-impl<T> Vector<T> for Vec2<T>
-	where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
-	fn dot(self, other: Self) -> T {
-		(self*other).sum_elem()
-	}
 	
-	fn distance(self, other: Self) -> T {
-		(self - other).magnitude()
-	}
-}
-
-impl<T: Mul<Output=T> + One> Product<Vec2<T>> for Vec2<T> {
-	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
-		iter.fold(Self::one(), |a, b| a * b)
-	}
-}
-
-impl<T: Add<Output=T> + Zero> Sum<Vec2<T>> for Vec2<T> {
-	fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
-		iter.fold(Self::zero(), |a, b| a + b)
-	}
-}
-
-impl Vec2<bool> {
-	pub fn and(self) -> bool {
-		self.x && self.y
+	//turned a quarter counter clockwise
+	pub fn perp(self) -> Self
+		where T: Neg<Output=T> {
+		vec2(-self.y, self.x)
 	}
 	
-	pub fn or(self) -> bool {
-		self.x || self.y
+	//the unsigned angle in [0, pi], from Kahan's formula, which stays accurate for nearly parallel
+	//and nearly opposite vectors where acos of the dot product loses precision or goes out of range
+	pub fn angle_between(self, other: Self) -> Rad<T>
+		where T: Copy + Two + Sqrt<T> + Trig + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let (u, v) = (self * other.magnitude(), other * self.magnitude());
+		Rad(T::two() * (u - v).magnitude().atan2((u + v).magnitude()))
 	}
-}
-
-pub fn vec2<T>(x: T, y: T) -> Vec2<T> {
-	Vec2 { x: x, y: y }
-}
-
-macro impl_ints1($($U: ident),*) {
-	$(
-		impl Vec2<isize> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i64> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i32> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i16> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i8> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-	)*
-}
-
-macro impl_ints2($($U: ident),*){
-	$(
-		impl Vec2<isize> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i64> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i32> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i16> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<i8> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-	)*
-}
-
-macro impl_floats1($($U: ident),*) {
-	$(
-		impl Vec2<f64> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<f32> {
-			pub fn $U(self) -> Self {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-	)*
-}
-
-macro impl_floats2($($U: ident),*) {
-	$(
-		impl Vec2<f64> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-		impl Vec2<f32> {
-			pub fn $U(self) -> Vec2<bool> {
-				vec2(self.x.$U(), self.y.$U())
-			}
-		}
-	)*
-}
-
-//component-wise functions
-//certain conversion and trig functions not implemented to avoid confusion
-impl_ints1!(abs,signum,swap_bytes/*,reverse_bits*/,to_be,to_le,wrapping_neg,wrapping_abs);
-impl_ints2!(is_positive,is_negative);
-impl_floats1!(floor,ceil,round,trunc,fract,abs,signum,sqrt,exp,exp2,ln,log2,log10,cbrt,exp_m1,ln_1p);
-impl_floats2!(is_nan,is_infinite,is_finite,is_normal,is_sign_positive,is_sign_negative);
-
-impl<T> Div<T> for Vec2<T>
-	where T: Copy + Div<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn div(self, scalar: T) -> Vec2<T> {
-		vec2(self.x / scalar, self.y / scalar)
+	//the part of self along other
+	pub fn project_onto(self, other: Self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		other * (dot(self, other) / dot(other, other))
 	}
-}
-
-impl<T> DivAssign<T> for Vec2<T>
-	where T: Copy + Div<Output=T> {
-	fn div_assign(&mut self, scalar: T) {
-		*self = *self / scalar;
-	}
-}
-
-impl<T> Div<Vec2<T>> for Vec2<T>
-	where T: Div<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn div(self, other: Vec2<T>) -> Vec2<T> {
-		vec2(self.x / other.x, self.y / other.y)
-	}
-}
-
-impl<T> DivAssign<Vec2<T>> for Vec2<T>
-	where T: Copy + Div<Output=T> {
-	fn div_assign(&mut self, other: Vec2<T>) {
-		*self = *self / other;
+	//the part of self perpendicular to other
+	pub fn reject_from(self, other: Self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self - self.project_onto(other)
 	}
-}
-
-impl<T> Mul<T> for Vec2<T>
-	where T: Copy + Mul<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn mul(self, scalar: T) -> Vec2<T> {
-		vec2(self.x * scalar, self.y * scalar)
-	}
-}
-
-impl<T> MulAssign<T> for Vec2<T>
-	where T: Copy + Mul<Output=T> {
-	fn mul_assign(&mut self, scalar: T) {
-		*self = *self * scalar;
+	//mirrors self in the plane (line) with the unit normal, as glsl reflect
+	pub fn reflect(self, normal: Self) -> Self
+		where T: Copy + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self - normal * (T::two() * dot(self, normal))
 	}
-}
-
-impl<T> Mul<Vec2<T>> for Vec2<T>
-	where T: Mul<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn mul(self, other: Vec2<T>) -> Vec2<T> {
-		vec2(self.x * other.x, self.y * other.y)
-	}
-}
-
-impl<T> MulAssign<Vec2<T>> for Vec2<T>
-	where T: Copy + Mul<Output=T> {
-	fn mul_assign(&mut self, other: Vec2<T>) {
-		*self = *self * other;
+	//bends the unit direction self through a surface with the unit normal (facing against self),
+	//eta is the ratio of the refractive indices, as glsl refract but None for total internal reflection
+	pub fn refract(self, normal: Self, eta: T) -> Option<Self>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let d = dot(self, normal);
+		let k = T::one() - eta * eta * (T::one() - d * d);
+		if k < T::zero() { return None; }
+		Some(self * eta - normal * (eta * d + k.sqrt()))
 	}
-}
-
-impl<T> Add<T> for Vec2<T>
-	where T: Copy + Add<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn add(self, scalar: T) -> Vec2<T> {
-		vec2(self.x + scalar, self.y + scalar)
-	}
-}
-
-impl<T> AddAssign<T> for Vec2<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, scalar: T) {
-		*self = *self + scalar;
+	//between unit directions at a constant angular speed, the result is a unit direction
+	//equal directions give self, opposite ones have two arcs between them and take the counter clockwise one
+	pub fn slerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Rad(theta) = self.angle_between(other);
+		if perp_dot(self, other) == T::zero() && dot(self, other) < T::zero() {
+			return self * (t * theta).cos() + self.perp() * (t * theta).sin();
+		}
+		let s = theta.sin();
+		if s == T::zero() { return self * (T::one() - t) + other * t; }
+		(self * ((T::one() - t) * theta).sin() + other * (t * theta).sin()) / s
 	}
-}
-
-impl<T> Add<Vec2<T>> for Vec2<T>
-	where T: Add<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn add(self, other: Vec2<T>) -> Vec2<T> {
-		vec2(self.x + other.x, self.y + other.y)
+	//the angle turning self onto other, positive counter clockwise, in (-pi, pi]
+	pub fn signed_angle_between(self, other: Self) -> Rad<T>
+		where T: Copy + Sqrt<T> + Trig + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		Rad::atan2(perp_dot(self, other), dot(self, other))
 	}
 }
 
-impl<T> AddAssign<Vec2<T>> for Vec2<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, other: Vec2<T>) {
-		*self = *self + other;
-	}
+//the z of the 3d cross product, or dot(a.perp(), b): positive when b is counter clockwise from a
+pub fn perp_dot<T>(a: Vec2<T>, b: Vec2<T>) -> T
+	where T: Mul<Output=T> + Sub<Output=T> {
+	a.x * b.y - a.y * b.x
 }
 
-impl<T> Sub<T> for Vec2<T>
-	where T: Copy + Sub<Output=T> {
-	type Output = Vec2<T>;
-	
-	fn sub(self, scalar: T) -> Vec2<T> {
-		vec2(self.x - scalar, self.y - scalar)
-	}
+pub fn angle_between<T>(a: Vec2<T>, b: Vec2<T>) -> Rad<T>
+	where T: Copy + Two + Trig + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+	a.angle_between(b)
 }
 
-impl<T> SubAssign<T> for Vec2<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, scalar: T) {
-		*self = *self - scalar;
+pub use crate::prelude::{dot,distance};
+impl<T> Vector<T> for Vec2<T>
+	where T: Copy + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+	fn dot(self, other: Self) -> T {
+		(self*other).sum_elem()
 	}
-}
-
-impl<T> Sub<Vec2<T>> for Vec2<T>
-	where T: Sub<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn sub(self, other: Vec2<T>) -> Vec2<T> {
-		vec2(self.x - other.x, self.y - other.y)
+	fn distance(self, other: Self) -> T {
+		(self - other).magnitude()
 	}
 }
 
-impl<T> SubAssign<Vec2<T>> for Vec2<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, other: Vec2<T>) {
-		*self = *self - other;
+impl Vec2<bool> {
+	pub fn and(self) -> bool {
+		self.x && self.y
 	}
-}
-
-impl<T> Rem<T> for Vec2<T>
-	where T: Copy + Rem<Output=T> {
-	type Output = Vec2<T>;
 	
-	fn rem(self, scalar: T) -> Vec2<T> {
-		vec2(self.x % scalar, self.y % scalar)
+	pub fn or(self) -> bool {
+		self.x || self.y
 	}
 }
 
-impl<T> RemAssign<T> for Vec2<T>
-	where T: Copy + Rem<Output=T> {
-	fn rem_assign(&mut self, scalar: T) {
-		*self = *self % scalar;
-	}
+pub fn vec2<T>(x: T, y: T) -> Vec2<T> {
+	Vec2 { x: x, y: y }
 }
 
 /*impl<T, U> Into<Vec2<U>> for Vec2<T>
@@ -385,14 +199,6 @@ impl<T> RemAssign<T> for Vec2<T>
 	}
 }*/
 
-impl<T> Default for Vec2<T>
-	where T: Default
-{
-	fn default() -> Self {
-		vec2(T::default(), T::default())
-	}
-}
-
 impl<T> Index<usize> for Vec2<T> {
 	type Output = T;
 	
@@ -405,7 +211,6 @@ impl<T> Index<usize> for Vec2<T> {
 	}
 }
 
-
 impl<T> IndexMut<usize> for Vec2<T> {
 	fn index_mut(&mut self, index: usize) -> &mut T {
 		match index {
@@ -416,11 +221,6 @@ impl<T> IndexMut<usize> for Vec2<T> {
 	}
 }
 
-impl<T: Neg> Neg for Vec2<T> {
-	type Output = Vec2<<T as Neg>::Output>;
-	fn neg(self) -> Vec2<<T as Neg>::Output> { vec2(-self.x,-self.y) }
-}
-
 impl<T> ArrayTuple for Vec2<T> {
 	type Array = [T; 2];
 	type Tuple = (T, T);
@@ -440,64 +240,3 @@ impl<T> From<[T; 2]> for Vec2<T> {
 	}
 }
 
-impl<T: fmt::Display> fmt::Display for Vec2<T> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if let Some(p) = f.precision() {
-			write!(f, "({2:.*}, {3:.*})", p, p, self.x, self.y)
-		} else {
-			write!(f, "({}, {})", self.x, self.y)
-		}
-	}
-}
-
-impl<T: NiceFmt> NiceFmt for Vec2<T> {
-	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
-		format!("({}, {})", self.x.nice_fmt(limit, pad), self.y.nice_fmt(limit, pad))
-	}
-}
-
-impl<T: fmt::LowerExp> fmt::LowerExp for Vec2<T> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if let Some(p) = f.precision() {
-			write!(f, "({2:.*e}, {3:.*e})", p, p, self.x, self.y)
-		} else {
-			write!(f, "({:e}, {:e})", self.x, self.y)
-		}
-	}
-}
-macro convert($T: ty, $($U: ident),*) {
-	$(
-		impl Vec2<$T> {
-			pub fn $U(self) -> Vec2<$U> {
-				vec2(self.x as $U, self.y as $U)
-			}
-		}
-	)*
-}
-
-convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
-
-impl<T: FromStr> FromStr for Vec2<T> {
-	type Err = <T as FromStr>::Err;
-	
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut n: Vec<&str> = s.split(|c: char| c.is_whitespace() || c == ',')
-			.map(|s| s.trim_matches(BRACKETS)).filter(|s| !s.is_empty()).collect();
-		while n.len() < 2 { n.push(""); }
-		let x = n[0].parse()?;
-		let y = n[1].parse()?;
-		Ok(vec2(x, y))
-	}
-}
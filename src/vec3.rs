@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 use crate::vec4::*;
+use crate::angle::*;
 use crate::vec2::*;
 
 #[repr(C)]
@@ -99,6 +100,59 @@ impl<T> Vec3<T> {
 	pub fn extend(self, w: T) -> Vec4<T> {
 		vec4(self.x, self.y, self.z, w)
 	}
+	
+	//in [0, pi], kahan's formula rather than acos(dot) so it holds up near 0 and pi
+	pub fn angle_between(self, other: Self) -> Rad<T>
+		where T: Copy + Two + Sqrt<T> + Trig + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let (u, v) = (self * other.magnitude(), other * self.magnitude());
+		Rad(T::two() * (u - v).magnitude().atan2((u + v).magnitude()))
+	}
+	
+	//the part of self along other
+	pub fn project_onto(self, other: Self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		other * (dot(self, other) / dot(other, other))
+	}
+	
+	//the part of self perpendicular to other
+	pub fn reject_from(self, other: Self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self - self.project_onto(other)
+	}
+	
+	//mirrors self in the plane with the unit normal
+	pub fn reflect(self, normal: Self) -> Self
+		where T: Copy + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self - normal * (T::two() * dot(self, normal))
+	}
+	
+	//see Vec2::refract, None for total internal reflection
+	pub fn refract(self, normal: Self, eta: T) -> Option<Self>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let d = dot(self, normal);
+		let k = T::one() - eta * eta * (T::one() - d * d);
+		if k < T::zero() { return None; }
+		Some(self * eta - normal * (eta * d + k.sqrt()))
+	}
+	
+	//along the great circle between unit directions, opposite ones go through any_perpendicular(self)
+	pub fn slerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Rad(theta) = self.angle_between(other);
+		if cross(self, other) == Vec3::zero() && dot(self, other) < T::zero() {
+			return self * (t * theta).cos() + any_perpendicular(self) * (t * theta).sin();
+		}
+		let s = theta.sin();
+		if s == T::zero() { return self * (T::one() - t) + other * t; }
+		(self * ((T::one() - t) * theta).sin() + other * (t * theta).sin()) / s
+	}
+	
+	//angle_between, negative when the turn from self to other is clockwise about axis by the right hand rule
+	pub fn signed_angle_around(self, other: Self, axis: Self) -> Rad<T>
+		where T: Copy + PartialOrd + Zero + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let angle = self.angle_between(other);
+		if dot(cross(self, other), axis) < T::zero() { -angle } else { angle }
+	}
 }
 
 pub use crate::prelude::{dot,distance};
@@ -113,18 +167,6 @@ impl<T> Vector<T> for Vec3<T>
 	}
 }
 
-impl<T: Mul<Output=T> + One> Product<Vec3<T>> for Vec3<T> {
-	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
-		iter.fold(Self::one(), |a, b| a * b)
-	}
-}
-
-impl<T: Add<Output=T> + Zero> Sum<Vec3<T>> for Vec3<T> {
-	fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
-		iter.fold(Self::zero(), |a, b| a + b)
-	}
-}
-
 impl Vec3<bool> {
 	pub fn and(self) -> bool {
 		self.x && self.y && self.z
@@ -135,103 +177,6 @@ impl Vec3<bool> {
 	}
 }
 
-macro impl_ints1($($U: ident),*) {
-	$(
-		impl Vec3<isize> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i64> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i32> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i16> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i8> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-	)*
-}
-
-macro impl_ints2($($U: ident),*) {
-	$(
-		impl Vec3<isize> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i64> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i32> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i16> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<i8> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-	)*
-}
-
-macro impl_floats1($($U: ident),*) {
-	$(
-		impl Vec3<f64> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<f32> {
-			pub fn $U(self) -> Self {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-	)*
-}
-
-macro impl_floats2($($U: ident),*) {
-	$(
-		impl Vec3<f64> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-		impl Vec3<f32> {
-			pub fn $U(self) -> Vec3<bool> {
-				vec3(self.x.$U(), self.y.$U(), self.z.$U())
-			}
-		}
-	)*
-}
-
-//component-wise functions
-//certain conversion and trig functions not implemented to avoid confusion
-impl_ints1!(abs,signum,swap_bytes/*,reverse_bits*/,to_be,to_le,wrapping_neg,wrapping_abs);
-impl_ints2!(is_positive,is_negative);
-impl_floats1!(floor,ceil,round,trunc,fract,abs,signum,sqrt,exp,exp2,ln,log2,log10,cbrt,exp_m1,ln_1p);
-impl_floats2!(is_nan,is_infinite,is_finite,is_normal,is_sign_positive,is_sign_negative);
-
 impl Vec3<f64> {
 	pub fn to_bits(self) -> Vec3<u64> {
 		vec3(self.x.to_bits(), self.y.to_bits(), self.z.to_bits())
@@ -253,156 +198,19 @@ pub fn cross<T>(v: Vec3<T>, u: Vec3<T>) -> Vec3<T>
 	vec3(v.y * u.z - v.z * u.y, v.z * u.x - v.x * u.z, v.x * u.y - v.y * u.x)
 }
 
-impl<T> Div<T> for Vec3<T>
-	where T: Copy + Div<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn div(self, scalar: T) -> Vec3<T> {
-		vec3(self.x / scalar, self.y / scalar, self.z / scalar)
-	}
-}
-
-impl<T> DivAssign<T> for Vec3<T>
-	where T: Copy + Div<Output=T> {
-	fn div_assign(&mut self, scalar: T) {
-		*self = *self / scalar;
-	}
-}
-
-impl<T> Div<Vec3<T>> for Vec3<T>
-	where T: Div<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn div(self, other: Vec3<T>) -> Vec3<T> {
-		vec3(self.x / other.x, self.y / other.y, self.z / other.z)
-	}
-}
-
-impl<T> DivAssign<Vec3<T>> for Vec3<T>
-	where T: Copy + Div<Output=T> {
-	fn div_assign(&mut self, other: Vec3<T>) {
-		*self = *self / other;
-	}
-}
-
-impl<T> Mul<T> for Vec3<T>
-	where T: Copy + Mul<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn mul(self, scalar: T) -> Vec3<T> {
-		vec3(self.x * scalar, self.y * scalar, self.z * scalar)
-	}
-}
-
-impl<T> MulAssign<T> for Vec3<T>
-	where T: Copy + Mul<Output=T> {
-	fn mul_assign(&mut self, scalar: T) {
-		*self = *self * scalar;
-	}
-}
-
-impl<T> Mul<Vec3<T>> for Vec3<T>
-	where T: Mul<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn mul(self, other: Vec3<T>) -> Vec3<T> {
-		vec3(self.x * other.x, self.y * other.y, self.z * other.z)
-	}
-}
-
-impl<T> MulAssign<Vec3<T>> for Vec3<T>
-	where T: Copy + Mul<Output=T> {
-	fn mul_assign(&mut self, other: Vec3<T>) {
-		*self = *self * other;
-	}
-}
-
-impl<T> Add<T> for Vec3<T>
-	where T: Copy + Add<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn add(self, scalar: T) -> Vec3<T> {
-		vec3(self.x + scalar, self.y + scalar, self.z + scalar)
-	}
-}
-
-impl<T> AddAssign<T> for Vec3<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, scalar: T) {
-		*self = *self + scalar;
-	}
-}
-
-impl<T> Add<Vec3<T>> for Vec3<T>
-	where T: Add<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn add(self, other: Vec3<T>) -> Vec3<T> {
-		vec3(self.x + other.x, self.y + other.y, self.z + other.z)
-	}
-}
-
-impl<T> AddAssign<Vec3<T>> for Vec3<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, other: Vec3<T>) {
-		*self = *self + other;
-	}
-}
-
-impl<T> Sub<T> for Vec3<T>
-	where T: Copy + Sub<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn sub(self, scalar: T) -> Vec3<T> {
-		vec3(self.x - scalar, self.y - scalar, self.z - scalar)
-	}
-}
-
-impl<T> SubAssign<T> for Vec3<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, scalar: T) {
-		*self = *self - scalar;
-	}
-}
-
-impl<T> Sub<Vec3<T>> for Vec3<T>
-	where T: Sub<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn sub(self, other: Vec3<T>) -> Vec3<T> {
-		vec3(self.x - other.x, self.y - other.y, self.z - other.z)
-	}
-}
-
-impl<T> SubAssign<Vec3<T>> for Vec3<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, other: Vec3<T>) {
-		*self = *self - other;
-	}
-}
-
-impl<T> Rem<T> for Vec3<T>
-	where T: Copy + Rem<Output=T> {
-	type Output = Vec3<T>;
-	
-	fn rem(self, scalar: T) -> Vec3<T> {
-		vec3(self.x % scalar, self.y % scalar, self.z % scalar)
-	}
-}
-
-impl<T> RemAssign<T> for Vec3<T>
-	where T: Copy + Rem<Output=T> {
-	fn rem_assign(&mut self, scalar: T) {
-		*self = *self % scalar;
-	}
-}
-
-impl<T> Default for Vec3<T>
-	where T: Default
-{
-	fn default() -> Self {
-		vec3(T::default(), T::default(), T::default())
-	}
+//any unit vector perpendicular to unit v
+pub fn any_perpendicular<T>(v: Vec3<T>) -> Vec3<T>
+	where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+	let a = vec3(abs(v.x), abs(v.y), abs(v.z));
+	//crossing with the axis v leans on least keeps the result well away from zero
+	let axis = if a.x <= a.y && a.x <= a.z {
+		vec3(T::one(), T::zero(), T::zero())
+	} else if a.y <= a.z {
+		vec3(T::zero(), T::one(), T::zero())
+	} else {
+		vec3(T::zero(), T::zero(), T::one())
+	};
+	cross(v, axis).normalize()
 }
 
 impl<T> Index<usize> for Vec3<T> {
@@ -429,11 +237,6 @@ impl<T> IndexMut<usize> for Vec3<T> {
 	}
 }
 
-impl<T: Neg> Neg for Vec3<T> {
-	type Output = Vec3<<T as Neg>::Output>;
-	fn neg(self) -> Vec3<<T as Neg>::Output> { vec3(-self.x,-self.y,-self.z) }
-}
-
 impl<T> ArrayTuple for Vec3<T> {
 	type Array = [T; 3];
 	type Tuple = (T, T, T);
@@ -453,66 +256,3 @@ impl<T> From<[T; 3]> for Vec3<T> {
 	}
 }
 
-impl<T: fmt::Display> fmt::Display for Vec3<T> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if let Some(p) = f.precision() {
-			write!(f, "({3:.*}, {4:.*}, {5:.*})", p, p, p, self.x, self.y, self.z)
-		} else {
-			write!(f, "({}, {}, {})", self.x, self.y, self.z)
-		}
-	}
-}
-
-impl<T: NiceFmt> NiceFmt for Vec3<T> {
-	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
-		format!("({}, {}, {})", self.x.nice_fmt(limit, pad), self.y.nice_fmt(limit, pad), self.z.nice_fmt(limit, pad))
-	}
-}
-
-impl<T: fmt::LowerExp> fmt::LowerExp for Vec3<T> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if let Some(p) = f.precision() {
-			write!(f, "({3:.*e}, {4:.*e}, {5:.*e})", p, p, p, self.x, self.y, self.z)
-		} else {
-			write!(f, "({:e}, {:e}, {:e})", self.x, self.y, self.z)
-		}
-	}
-}
-
-macro convert($T: ty, $($U: ident),*) {
-	$(
-		impl Vec3<$T> {
-			pub fn $U(self) -> Vec3<$U> {
-				vec3(self.x as $U, self.y as $U, self.z as $U)
-			}
-		}
-	)*
-}
-
-convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
-
-impl<T: FromStr> FromStr for Vec3<T> {
-	type Err = <T as FromStr>::Err;
-	
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut n: Vec<&str> = s.split(|c: char| c.is_whitespace() || c == ',')
-			.map(|s| s.trim_matches(BRACKETS)).filter(|s| !s.is_empty()).collect();
-		while n.len() < 3 { n.push(""); }
-		let x = n[0].parse()?;
-		let y = n[1].parse()?;
-		let z = n[2].parse()?;
-		Ok(vec3(x, y, z))
-	}
-}
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 use crate::vec3::*;
+use crate::angle::*;
 
 #[repr(C)]
 #[derive(Debug,Copy,Clone,PartialEq,Eq,Hash,Serialize,Deserialize)]
@@ -97,6 +98,60 @@ impl<T> Vec4<T> {
 	pub fn downsize(self) -> Vec3<T> {
 		vec3(self.x, self.y, self.z)
 	}
+	
+	//in [0, pi], see Vec3::angle_between
+	pub fn angle_between(self, other: Self) -> Rad<T>
+		where T: Copy + Two + Sqrt<T> + Trig + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let (u, v) = (self * other.magnitude(), other * self.magnitude());
+		Rad(T::two() * (u - v).magnitude().atan2((u + v).magnitude()))
+	}
+	
+	pub fn project_onto(self, other: Self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		other * (dot(self, other) / dot(other, other))
+	}
+	
+	pub fn reject_from(self, other: Self) -> Self
+		where T: Copy + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		self - self.project_onto(other)
+	}
+	
+	//mirrors self in the hyperplane with the unit normal
+	pub fn reflect(self, normal: Self) -> Self
+		where T: Copy + Two + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		self - normal * (T::two() * dot(self, normal))
+	}
+	
+	//see Vec2::refract
+	pub fn refract(self, normal: Self, eta: T) -> Option<Self>
+		where T: Copy + PartialOrd + Zero + One + Sqrt<T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+		let d = dot(self, normal);
+		let k = T::one() - eta * eta * (T::one() - d * d);
+		if k < T::zero() { return None; }
+		Some(self * eta - normal * (eta * d + k.sqrt()))
+	}
+	
+	//see Vec3::slerp, opposite directions go through the axis self leans on least with self rejected from it
+	pub fn slerp(self, other: Self, t: T) -> Self
+		where T: Copy + PartialOrd + Zero + One + Two + Sqrt<T> + Trig + Neg<Output=T> + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+		let Rad(theta) = self.angle_between(other);
+		if other.reject_from(self) == Vec4::zero() && dot(self, other) < T::zero() {
+			let a = vec4(abs(self.x), abs(self.y), abs(self.z), abs(self.w));
+			let axis = if a.x <= a.y && a.x <= a.z && a.x <= a.w {
+				vec4(T::one(), T::zero(), T::zero(), T::zero())
+			} else if a.y <= a.z && a.y <= a.w {
+				vec4(T::zero(), T::one(), T::zero(), T::zero())
+			} else if a.z <= a.w {
+				vec4(T::zero(), T::zero(), T::one(), T::zero())
+			} else {
+				vec4(T::zero(), T::zero(), T::zero(), T::one())
+			};
+			return self * (t * theta).cos() + axis.reject_from(self).normalise() * (t * theta).sin();
+		}
+		let s = theta.sin();
+		if s == T::zero() { return self * (T::one() - t) + other * t; }
+		(self * ((T::one() - t) * theta).sin() + other * (t * theta).sin()) / s
+	}
 }
 
 pub use crate::prelude::{dot,distance};
@@ -111,18 +166,6 @@ impl<T> Vector<T> for Vec4<T>
 	}
 }
 
-impl<T: Mul<Output=T> + One> Product<Vec4<T>> for Vec4<T> {
-	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
-		iter.fold(Self::one(), |a, b| a * b)
-	}
-}
-
-impl<T: Add<Output=T> + Zero> Sum<Vec4<T>> for Vec4<T> {
-	fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
-		iter.fold(Self::zero(), |a, b| a + b)
-	}
-}
-
 impl<T: One> From<Vec3<T>> for Vec4<T> {
 	fn from(v: Vec3<T>) -> Self {
 		vec4(v.x,v.y,v.z,T::one())
@@ -143,103 +186,6 @@ impl Vec4<bool> {
 	}
 }
 
-macro impl_ints1($($U: ident),*) {
-	$(
-		impl Vec4<isize> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i64> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i32> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i16> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i8> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-	)*
-}
-
-macro impl_ints2($($U: ident),*) {
-	$(
-		impl Vec4<isize> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i64> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i32> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i16> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<i8> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-	)*
-}
-
-macro impl_floats1($($U: ident),*) {
-	$(
-		impl Vec4<f64> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<f32> {
-			pub fn $U(self) -> Self {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-	)*
-}
-
-macro impl_floats2($($U: ident),*) {
-	$(
-		impl Vec4<f64> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-		impl Vec4<f32> {
-			pub fn $U(self) -> Vec4<bool> {
-				vec4(self.x.$U(), self.y.$U(), self.z.$U(), self.w.$U())
-			}
-		}
-	)*
-}
-
-//component-wise functions
-//certain conversion and trig functions not implemented to avoid confusion
-impl_ints1!(abs,signum,swap_bytes/*,reverse_bits*/,to_be,to_le,wrapping_neg,wrapping_abs);
-impl_ints2!(is_positive,is_negative);
-impl_floats1!(floor,ceil,round,trunc,fract,abs,signum,sqrt,exp,exp2,ln,log2,log10,cbrt,exp_m1,ln_1p);
-impl_floats2!(is_nan,is_infinite,is_finite,is_normal,is_sign_positive,is_sign_negative);
-
 impl Vec4<u16> {
 	pub fn into_workaround(self) -> Vec4<f64> {
 		vec4(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
@@ -252,150 +198,6 @@ impl Vec4<f64> {
 	}
 }
 
-impl<T> Div<T> for Vec4<T>
-	where T: Copy + Div<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn div(self, scalar: T) -> Vec4<T> {
-		vec4(self.x / scalar, self.y / scalar, self.z / scalar, self.w / scalar)
-	}
-}
-
-impl<T> DivAssign<T> for Vec4<T>
-	where T: Copy + Div<Output=T> {
-	fn div_assign(&mut self, scalar: T) {
-		*self = *self / scalar;
-	}
-}
-
-impl<T> Div<Vec4<T>> for Vec4<T>
-	where T: Div<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn div(self, other: Vec4<T>) -> Vec4<T> {
-		vec4(self.x / other.x, self.y / other.y, self.z / other.z, self.w / other.w)
-	}
-}
-
-impl<T> DivAssign<Vec4<T>> for Vec4<T>
-	where T: Copy + Div<Output=T> {
-	fn div_assign(&mut self, other: Vec4<T>) {
-		*self = *self / other;
-	}
-}
-
-impl<T> Mul<T> for Vec4<T>
-	where T: Copy + Mul<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn mul(self, scalar: T) -> Vec4<T> {
-		vec4(self.x * scalar, self.y * scalar, self.z * scalar, self.w * scalar)
-	}
-}
-
-impl<T> MulAssign<T> for Vec4<T>
-	where T: Copy + Mul<Output=T> {
-	fn mul_assign(&mut self, scalar: T) {
-		*self = *self * scalar;
-	}
-}
-
-impl<T> Mul<Vec4<T>> for Vec4<T>
-	where T: Mul<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn mul(self, other: Vec4<T>) -> Vec4<T> {
-		vec4(self.x * other.x, self.y * other.y, self.z * other.z, self.w * other.w)
-	}
-}
-
-impl<T> MulAssign<Vec4<T>> for Vec4<T>
-	where T: Copy + Mul<Output=T> {
-	fn mul_assign(&mut self, other: Vec4<T>) {
-		*self = *self * other;
-	}
-}
-
-impl<T> Add<T> for Vec4<T>
-	where T: Copy + Add<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn add(self, scalar: T) -> Vec4<T> {
-		vec4(self.x + scalar, self.y + scalar, self.z + scalar, self.w + scalar)
-	}
-}
-
-impl<T> AddAssign<T> for Vec4<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, scalar: T) {
-		*self = *self + scalar;
-	}
-}
-
-impl<T> Add<Vec4<T>> for Vec4<T>
-	where T: Add<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn add(self, other: Vec4<T>) -> Vec4<T> {
-		vec4(self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w)
-	}
-}
-
-impl<T> AddAssign<Vec4<T>> for Vec4<T>
-	where T: Copy + Add<Output=T> {
-	fn add_assign(&mut self, other: Vec4<T>) {
-		*self = *self + other;
-	}
-}
-
-impl<T> Sub<T> for Vec4<T>
-	where T: Copy + Sub<Output=T> {
-	type Output = Vec4<T>;
-	
-	fn sub(self, scalar: T) -> Vec4<T> {
-		vec4(self.x - scalar, self.y - scalar, self.z - scalar, self.w - scalar)
-	}
-}
-
-impl<T> SubAssign<T> for Vec4<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, scalar: T) {
-		*self = *self - scalar;
-	}
-}
-
-impl<T> Sub<Self> for Vec4<T>
-	where T: Sub<Output=T> {
-	type Output = Self;
-	
-	fn sub(self, other: Self) -> Self {
-		vec4(self.x - other.x, self.y - other.y, self.z - other.z, self.w - other.w)
-	}
-}
-
-impl<T> SubAssign<Self> for Vec4<T>
-	where T: Copy + Sub<Output=T> {
-	fn sub_assign(&mut self, other: Self) {
-		*self = *self - other;
-	}
-}
-
-impl<T> Rem<T> for Vec4<T>
-	where T: Copy + Rem<Output=T> {
-	type Output = Self;
-	
-	fn rem(self, scalar: T) -> Self {
-		vec4(self.x % scalar, self.y % scalar, self.z % scalar, self.w % scalar)
-	}
-}
-
-impl<T> RemAssign<T> for Vec4<T>
-	where T: Copy + Rem<Output=T> {
-	fn rem_assign(&mut self, scalar: T) {
-		*self = *self % scalar;
-	}
-}
-
 /*impl<T, U> Into<Vec4<U>> for Vec4<T>
 	where T: Into<U> {
 	fn from(v: Vec4<T>) -> Vec4<U> {
@@ -403,14 +205,6 @@ impl<T> RemAssign<T> for Vec4<T>
 	}
 }*/
 
-impl<T> Default for Vec4<T>
-	where T: Default
-{
-	fn default() -> Self {
-		vec4(T::default(), T::default(), T::default(), T::default())
-	}
-}
-
 impl<T> Index<usize> for Vec4<T> {
 	type Output = T;
 	
@@ -425,7 +219,6 @@ impl<T> Index<usize> for Vec4<T> {
 	}
 }
 
-
 impl<T> IndexMut<usize> for Vec4<T> {
 	fn index_mut(&mut self, index: usize) -> &mut T {
 		match index {
@@ -438,11 +231,6 @@ impl<T> IndexMut<usize> for Vec4<T> {
 	}
 }
 
-impl<T: Neg> Neg for Vec4<T> {
-	type Output = Vec4<<T as Neg>::Output>;
-	fn neg(self) -> Vec4<<T as Neg>::Output> { vec4(-self.x,-self.y,-self.z,-self.w) }
-}
-
 impl<T> ArrayTuple for Vec4<T> {
 	type Array = [T; 4];
 	type Tuple = (T, T, T, T);
@@ -462,67 +250,3 @@ impl<T> From<[T; 4]> for Vec4<T> {
 	}
 }
 
-impl<T: fmt::Display> fmt::Display for Vec4<T> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if let Some(p) = f.precision() {
-			write!(f, "({4:.*}, {5:.*}, {6:.*}, {7:.*})", p, p, p, p, self.x, self.y, self.z, self.w)
-		} else {
-			write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
-		}
-	}
-}
-
-impl<T: NiceFmt> NiceFmt for Vec4<T> {
-	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
-		format!("({}, {}, {}, {})", self.x.nice_fmt(limit, pad), self.y.nice_fmt(limit, pad), self.z.nice_fmt(limit, pad), self.w.nice_fmt(limit, pad))
-	}
-}
-
-impl<T: fmt::LowerExp> fmt::LowerExp for Vec4<T> {
-	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		if let Some(p) = f.precision() {
-			write!(f, "({4:.*e}, {5:.*e}, {6:.*e}, {7:.*e})", p, p, p, p, self.x, self.y, self.z, self.w)
-		} else {
-			write!(f, "({:e}, {:e}, {:e}, {:e})", self.x, self.y, self.z, self.w)
-		}
-	}
-}
-
-macro convert($T: ty, $($U: ident),*) {
-	$(
-		impl Vec4<$T> {
-			pub fn $U(self) -> Vec4<$U> {
-				vec4(self.x as $U, self.y as $U, self.z as $U, self.w as $U)
-			}
-		}
-	)*
-}
-
-convert!(u8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(u64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(usize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i8,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i16,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(i64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(isize,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f32,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(f64,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64);
-convert!(bool,u8,u16,u32,u64,usize,i8,i16,i32,i64,isize);
-
-impl<T: FromStr> FromStr for Vec4<T> {
-	type Err = <T as FromStr>::Err;
-	
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut n: Vec<&str> = s.split(|c: char| c.is_whitespace() || c == ',')
-			.map(|s| s.trim_matches(BRACKETS)).filter(|s| !s.is_empty()).collect();
-		while n.len() < 4 { n.push(""); }
-		let x = n[0].parse()?;
-		let y = n[1].parse()?;
-		let z = n[2].parse()?;
-		let w = n[3].parse()?;
-		Ok(vec4(x, y, z, w))
-	}
-}
@@ -0,0 +1,502 @@
+use crate::prelude::*;
+
+use crate::vec2::*;
+use crate::vec3::*;
+use crate::vec4::*;
+
+use std::array;
+use std::marker::PhantomData;
+use serde::{Serializer,Deserializer};
+use serde::ser::SerializeTuple;
+use serde::de::{self,Visitor,SeqAccess};
+
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> VecN<T, N> {
+	pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> VecN<U, N> {
+		VecN(self.0.map(f))
+	}
+	
+	pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: VecN<U, N>, mut f: F) -> VecN<V, N> {
+		let mut i = IntoIterator::into_iter(self.0).zip(other.0);
+		VecN(array::from_fn(|_| { let (a, b) = i.next().unwrap(); f(a, b) }))
+	}
+	
+	pub fn magnitude(self) -> T
+		where T: Copy + Zero + Sqrt<T> + Mul<Output=T> + Add<Output=T> {
+		(self * self).sum_elem().sqrt()
+	}
+	
+	pub fn normalize(self) -> Self
+		where T: Copy + Zero + Sqrt<T> + Div<Output=T> + Mul<Output=T> + Add<Output=T> {
+		self / self.magnitude()
+	}
+	
+	pub fn zero() -> Self
+		where T: Zero {
+		VecN(array::from_fn(|_| T::zero()))
+	}
+	
+	pub fn one() -> Self
+		where T: One {
+		VecN(array::from_fn(|_| T::one()))
+	}
+	
+	pub fn max(self, other: Self) -> Self
+		where T: IsNan {
+		self.zip_with(other, T::non_nan_max)
+	}
+	
+	pub fn min(self, other: Self) -> Self
+		where T: IsNan {
+		self.zip_with(other, T::non_nan_min)
+	}
+	
+	pub fn max_elem(self) -> T
+		where T: IsNan {
+		let mut i = IntoIterator::into_iter(self.0);
+		let first = i.next().expect("max_elem of an empty vector");
+		i.fold(first, T::non_nan_max)
+	}
+	
+	pub fn min_elem(self) -> T
+		where T: IsNan {
+		let mut i = IntoIterator::into_iter(self.0);
+		let first = i.next().expect("min_elem of an empty vector");
+		i.fold(first, T::non_nan_min)
+	}
+	
+	pub fn sum_elem(self) -> T
+		where T: Zero + Add<Output=T> {
+		IntoIterator::into_iter(self.0).fold(T::zero(), |a, b| a + b)
+	}
+	
+	pub fn mul_elem(self) -> T
+		where T: One + Mul<Output=T> {
+		IntoIterator::into_iter(self.0).fold(T::one(), |a, b| a * b)
+	}
+	
+	pub fn convert<U>(self) -> VecN<U, N>
+		where T: Into<U> {
+		self.map(T::into)
+	}
+}
+
+pub fn vecn<T, const N: usize>(a: [T; N]) -> VecN<T, N> {
+	VecN(a)
+}
+
+impl<T, const N: usize> Vector<T> for VecN<T, N>
+	where T: Copy + Zero + Sqrt<T> + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+	fn dot(self, other: Self) -> T {
+		(self*other).sum_elem()
+	}
+	
+	fn distance(self, other: Self) -> T {
+		(self - other).magnitude()
+	}
+}
+
+impl<T: Mul<Output=T> + One, const N: usize> Product<VecN<T, N>> for VecN<T, N> {
+	fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::one(), |a, b| a * b)
+	}
+}
+
+impl<T: Add<Output=T> + Zero, const N: usize> Sum<VecN<T, N>> for VecN<T, N> {
+	fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+		iter.fold(Self::zero(), |a, b| a + b)
+	}
+}
+
+impl<const N: usize> VecN<bool, N> {
+	pub fn and(self) -> bool {
+		self.0.iter().all(|&b| b)
+	}
+	
+	pub fn or(self) -> bool {
+		self.0.iter().any(|&b| b)
+	}
+}
+
+macro impl_ops($($Op: ident, $OpAssign: ident, $op: ident, $op_assign: ident, $sym: tt);*) {
+	$(
+		impl<T, const N: usize> $Op<T> for VecN<T, N>
+			where T: Copy + $Op<Output=T> {
+			type Output = VecN<T, N>;
+			
+			fn $op(self, scalar: T) -> VecN<T, N> {
+				self.map(|a| a $sym scalar)
+			}
+		}
+		
+		impl<T, const N: usize> $OpAssign<T> for VecN<T, N>
+			where T: Copy + $Op<Output=T> {
+			fn $op_assign(&mut self, scalar: T) {
+				*self = *self $sym scalar;
+			}
+		}
+		
+		impl<T, const N: usize> $Op<VecN<T, N>> for VecN<T, N>
+			where T: $Op<Output=T> {
+			type Output = VecN<T, N>;
+			
+			fn $op(self, other: VecN<T, N>) -> VecN<T, N> {
+				self.zip_with(other, |a, b| a $sym b)
+			}
+		}
+		
+		impl<T, const N: usize> $OpAssign<VecN<T, N>> for VecN<T, N>
+			where T: Copy + $Op<Output=T> {
+			fn $op_assign(&mut self, other: VecN<T, N>) {
+				*self = *self $sym other;
+			}
+		}
+	)*
+}
+
+impl_ops!(
+	Add, AddAssign, add, add_assign, +;
+	Sub, SubAssign, sub, sub_assign, -;
+	Mul, MulAssign, mul, mul_assign, *;
+	Div, DivAssign, div, div_assign, /;
+	Rem, RemAssign, rem, rem_assign, %
+);
+
+impl<T: Default, const N: usize> Default for VecN<T, N> {
+	fn default() -> Self {
+		VecN(array::from_fn(|_| T::default()))
+	}
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+	type Output = T;
+	
+	fn index(&self, index: usize) -> &T {
+		&self.0[index]
+	}
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		&mut self.0[index]
+	}
+}
+
+impl<T: Neg, const N: usize> Neg for VecN<T, N> {
+	type Output = VecN<<T as Neg>::Output, N>;
+	fn neg(self) -> VecN<<T as Neg>::Output, N> { self.map(|a| -a) }
+}
+
+macro impl_array_tuple($($N: expr),*) {
+	$(
+		impl<T> ArrayTuple for VecN<T, $N> {
+			type Array = [T; $N];
+			type Tuple = <[T; $N] as ArrayTuple>::Tuple;
+			fn into_array(self) -> [T; $N] { self.0 }
+			fn into_tuple(self) -> Self::Tuple { self.0.into_tuple() }
+		}
+	)*
+}
+
+impl_array_tuple!(2, 3, 4);
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+	fn from(a: [T; N]) -> Self {
+		VecN(a)
+	}
+}
+
+//the named vectors get their operators, formatting and parsing by going through VecN
+macro impl_named($($V: ident, $N: expr, $f: ident => ($($c: ident),*));*) {
+	$(
+		impl<T> From<$V<T>> for VecN<T, $N> {
+			fn from(v: $V<T>) -> Self {
+				VecN(v.into_array())
+			}
+		}
+		
+		impl<T> From<VecN<T, $N>> for $V<T> {
+			fn from(v: VecN<T, $N>) -> Self {
+				let [$($c),*] = v.0;
+				$f($($c),*)
+			}
+		}
+		
+		//borrows the elements, for formatting without Copy
+		impl<'a, T> From<&'a $V<T>> for VecN<&'a T, $N> {
+			fn from(v: &'a $V<T>) -> Self {
+				VecN([$(&v.$c),*])
+			}
+		}
+		
+		impl<T: Default> Default for $V<T> {
+			fn default() -> Self {
+				VecN::<T, $N>::default().into()
+			}
+		}
+		
+		impl<T: Neg> Neg for $V<T> {
+			type Output = $V<<T as Neg>::Output>;
+			fn neg(self) -> $V<<T as Neg>::Output> { (-VecN::<T, $N>::from(self)).into() }
+		}
+		
+		impl<T: Mul<Output=T> + One> Product<$V<T>> for $V<T> {
+			fn product<I: Iterator<Item=Self>>(iter: I) -> Self {
+				iter.map(VecN::<T, $N>::from).product::<VecN<T, $N>>().into()
+			}
+		}
+		
+		impl<T: Add<Output=T> + Zero> Sum<$V<T>> for $V<T> {
+			fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+				iter.map(VecN::<T, $N>::from).sum::<VecN<T, $N>>().into()
+			}
+		}
+		
+		impl<T: fmt::Display> fmt::Display for $V<T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::Display::fmt(&VecN::from(self), f)
+			}
+		}
+		
+		impl<T: NiceFmt> NiceFmt for $V<T> {
+			fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+				VecN::from(self).nice_fmt(limit, pad)
+			}
+		}
+		
+		impl<T: fmt::LowerExp> fmt::LowerExp for $V<T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				fmt::LowerExp::fmt(&VecN::from(self), f)
+			}
+		}
+		
+		impl<T: FromStr> FromStr for $V<T> {
+			type Err = <T as FromStr>::Err;
+			
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				s.parse::<VecN<T, $N>>().map(Self::from)
+			}
+		}
+	)*
+}
+
+impl_named!(Vec2, 2, vec2 => (x, y); Vec3, 3, vec3 => (x, y, z); Vec4, 4, vec4 => (x, y, z, w));
+
+macro impl_named_op($Op: ident, $OpAssign: ident, $op: ident, $op_assign: ident, $sym: tt; $($V: ident, $N: expr);*) {
+	$(
+		impl<T> $Op<T> for $V<T>
+			where T: Copy + $Op<Output=T> {
+			type Output = $V<T>;
+			
+			fn $op(self, scalar: T) -> $V<T> {
+				(VecN::<T, $N>::from(self) $sym scalar).into()
+			}
+		}
+		
+		impl<T> $OpAssign<T> for $V<T>
+			where T: Copy + $Op<Output=T> {
+			fn $op_assign(&mut self, scalar: T) {
+				*self = *self $sym scalar;
+			}
+		}
+		
+		impl<T> $Op<$V<T>> for $V<T>
+			where T: $Op<Output=T> {
+			type Output = $V<T>;
+			
+			fn $op(self, other: $V<T>) -> $V<T> {
+				(VecN::<T, $N>::from(self) $sym VecN::from(other)).into()
+			}
+		}
+		
+		impl<T> $OpAssign<$V<T>> for $V<T>
+			where T: Copy + $Op<Output=T> {
+			fn $op_assign(&mut self, other: $V<T>) {
+				*self = *self $sym other;
+			}
+		}
+	)*
+}
+
+macro impl_named_ops($($Op: ident, $OpAssign: ident, $op: ident, $op_assign: ident, $sym: tt);*) {
+	$(
+		impl_named_op!($Op, $OpAssign, $op, $op_assign, $sym; Vec2, 2; Vec3, 3; Vec4, 4);
+	)*
+}
+
+impl_named_ops!(
+	Add, AddAssign, add, add_assign, +;
+	Sub, SubAssign, sub, sub_assign, -;
+	Mul, MulAssign, mul, mul_assign, *;
+	Div, DivAssign, div, div_assign, /;
+	Rem, RemAssign, rem, rem_assign, %
+);
+
+macro impl_convert($($T: ty => $($U: ident),*);*) {
+	$($(
+		impl<const N: usize> VecN<$T, N> {
+			pub fn $U(self) -> VecN<$U, N> {
+				self.map(|a| a as $U)
+			}
+		}
+		impl Vec2<$T> {
+			pub fn $U(self) -> Vec2<$U> {
+				VecN::<$T, 2>::from(self).$U().into()
+			}
+		}
+		impl Vec3<$T> {
+			pub fn $U(self) -> Vec3<$U> {
+				VecN::<$T, 3>::from(self).$U().into()
+			}
+		}
+		impl Vec4<$T> {
+			pub fn $U(self) -> Vec4<$U> {
+				VecN::<$T, 4>::from(self).$U().into()
+			}
+		}
+	)*)*
+}
+
+impl_convert!(
+	u8 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	u16 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	u32 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	u64 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	usize => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i8 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i16 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i32 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	i64 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	isize => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	f32 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	f64 => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize,f32,f64;
+	bool => u8,u16,u32,u64,usize,i8,i16,i32,i64,isize
+);
+
+macro impl_map_one($T: ty => $Out: ty; ($($U: ident),*)) {
+	$(
+		impl<const N: usize> VecN<$T, N> {
+			pub fn $U(self) -> VecN<$Out, N> {
+				self.map(|a| a.$U())
+			}
+		}
+		impl Vec2<$T> {
+			pub fn $U(self) -> Vec2<$Out> {
+				VecN::<$T, 2>::from(self).$U().into()
+			}
+		}
+		impl Vec3<$T> {
+			pub fn $U(self) -> Vec3<$Out> {
+				VecN::<$T, 3>::from(self).$U().into()
+			}
+		}
+		impl Vec4<$T> {
+			pub fn $U(self) -> Vec4<$Out> {
+				VecN::<$T, 4>::from(self).$U().into()
+			}
+		}
+	)*
+}
+
+//component-wise functions, the elements of T map to Out
+macro impl_map($($T: ty => $Out: ty),*; $fns: tt) {
+	$(
+		impl_map_one!($T => $Out; $fns);
+	)*
+}
+
+//certain conversion and trig functions not implemented to avoid confusion
+impl_map!(isize => isize, i64 => i64, i32 => i32, i16 => i16, i8 => i8;
+	(abs,signum,swap_bytes/*,reverse_bits*/,to_be,to_le,wrapping_neg,wrapping_abs));
+impl_map!(isize => bool, i64 => bool, i32 => bool, i16 => bool, i8 => bool; (is_positive,is_negative));
+impl_map!(f64 => f64, f32 => f32; (floor,ceil,round,trunc,fract,abs,signum,sqrt,exp,exp2,ln,log2,log10,cbrt,exp_m1,ln_1p));
+impl_map!(f64 => bool, f32 => bool; (is_nan,is_infinite,is_finite,is_normal,is_sign_positive,is_sign_negative));
+
+impl<T: fmt::Display, const N: usize> fmt::Display for VecN<T, N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "(")?;
+		for (i, a) in self.0.iter().enumerate() {
+			if i > 0 { write!(f, ", ")?; }
+			if let Some(p) = f.precision() {
+				write!(f, "{:.*}", p, a)?;
+			} else {
+				write!(f, "{}", a)?;
+			}
+		}
+		write!(f, ")")
+	}
+}
+
+impl<T: NiceFmt, const N: usize> NiceFmt for VecN<T, N> {
+	fn nice_fmt(&self, limit: usize, pad: bool) -> String {
+		let elems: Vec<String> = self.0.iter().map(|a| a.nice_fmt(limit, pad)).collect();
+		format!("({})", elems.join(", "))
+	}
+}
+
+impl<T: fmt::LowerExp, const N: usize> fmt::LowerExp for VecN<T, N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "(")?;
+		for (i, a) in self.0.iter().enumerate() {
+			if i > 0 { write!(f, ", ")?; }
+			if let Some(p) = f.precision() {
+				write!(f, "{:.*e}", p, a)?;
+			} else {
+				write!(f, "{:e}", a)?;
+			}
+		}
+		write!(f, ")")
+	}
+}
+
+impl<T: FromStr, const N: usize> FromStr for VecN<T, N> {
+	type Err = <T as FromStr>::Err;
+	
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut n: Vec<&str> = s.split(|c: char| c.is_whitespace() || c == ',')
+			.map(|s| s.trim_matches(BRACKETS)).filter(|s| !s.is_empty()).collect();
+		while n.len() < N { n.push(""); }
+		let parsed = n[..N].iter().map(|s| s.parse()).collect::<Result<Vec<T>, _>>()?;
+		let mut i = parsed.into_iter();
+		Ok(VecN(array::from_fn(|_| i.next().unwrap())))
+	}
+}
+
+//serde only derives for arrays of a fixed length, so these are written out as a tuple of N elements
+impl<T: Serialize, const N: usize> Serialize for VecN<T, N> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut t = serializer.serialize_tuple(N)?;
+		for a in self.0.iter() {
+			t.serialize_element(a)?;
+		}
+		t.end()
+	}
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for VecN<T, N> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct VecNVisitor<T, const N: usize>(PhantomData<T>);
+		
+		impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for VecNVisitor<T, N> {
+			type Value = VecN<T, N>;
+			
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				write!(f, "a tuple of {} elements", N)
+			}
+			
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<VecN<T, N>, A::Error> {
+				let mut elems = Vec::with_capacity(N);
+				for i in 0..N {
+					elems.push(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?);
+				}
+				let mut i = elems.into_iter();
+				Ok(VecN(array::from_fn(|_| i.next().unwrap())))
+			}
+		}
+		
+		deserializer.deserialize_tuple(N, VecNVisitor(PhantomData))
+	}
+}
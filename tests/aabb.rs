@@ -0,0 +1,50 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::aabb::*;
+
+#[test]
+fn aabb3_queries() {
+	let b = Aabb3::from_points(vec![vec3(1.0, -2.0, 0.0), vec3(-1.0, 2.0, 4.0), vec3(0.0, 0.0, 1.0)]).unwrap();
+	assert_eq!(b, aabb3(vec3(-1.0, -2.0, 0.0), vec3(1.0, 2.0, 4.0)));
+	assert_eq!(Aabb3::<f64>::from_points(vec![]), None);
+	
+	assert_eq!(b.center(), vec3(0.0, 0.0, 2.0));
+	assert_eq!(b.extents(), vec3(2.0, 4.0, 4.0));
+	assert_eq!(b.volume(), 32.0);
+	assert_eq!(b.surface_area(), 2.0 * (8.0 + 16.0 + 8.0));
+	assert_eq!(b.expand(1.0), aabb3(vec3(-2.0, -3.0, -1.0), vec3(2.0, 3.0, 5.0)));
+	
+	assert!(b.contains_point(vec3(1.0, 2.0, 4.0)));
+	assert!(!b.contains_point(vec3(1.5, 0.0, 0.0)));
+	assert!(b.contains_box(aabb3(vec3(0.0, 0.0, 1.0), vec3(1.0, 1.0, 2.0))));
+	assert_eq!(b.closest_point(vec3(5.0, 0.0, -3.0)), vec3(1.0, 0.0, 0.0));
+	assert_eq!(b.closest_point(vec3(0.5, 0.5, 0.5)), vec3(0.5, 0.5, 0.5));
+	
+	let c = aabb3(vec3(0.0, 1.0, 3.0), vec3(5.0, 5.0, 5.0));
+	assert_eq!(b.union(c), aabb3(vec3(-1.0, -2.0, 0.0), vec3(5.0, 5.0, 5.0)));
+	assert_eq!(b.intersection(c), Some(aabb3(vec3(0.0, 1.0, 3.0), vec3(1.0, 2.0, 4.0))));
+	assert_eq!(b.intersection(aabb3(vec3(2.0, 0.0, 0.0), vec3(3.0, 1.0, 1.0))), None);
+}
+
+#[test]
+fn aabb_transform() {
+	let b = aabb3(vec3(-1.0, -1.0, -1.0), vec3(1.0, 2.0, 1.0));
+	let m = Mat4::translation(vec3(10.0, 0.0, 0.0)) * Mat4::scale(vec3(2.0, 1.0, 1.0));
+	assert_eq!(b.transform(m), aabb3(vec3(8.0, -1.0, -1.0), vec3(12.0, 2.0, 1.0)));
+	
+	//a quarter turn about z swaps x and y
+	let r = Mat4::from_rotation(mat3(vec3(0.0, -1.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0)));
+	assert_eq!(b.transform(r), aabb3(vec3(-2.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0)));
+	let corners = Aabb3::from_points(b.corners().iter().map(|&p| r.transform_point3(p))).unwrap();
+	assert_eq!(b.transform(r), corners);
+	
+	let b = aabb2(vec2(0, 0), vec2(2, 3));
+	assert_eq!(b.area(), 6);
+	assert_eq!(b.perimeter(), 10);
+	let m = mat3(vec3(-1, 0, 5), vec3(0, 1, 1), vec3(0, 0, 1));
+	assert_eq!(b.transform(m), aabb2(vec2(3, 1), vec2(5, 4)));
+}
@@ -0,0 +1,73 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::quaternion::*;
+use math_lib::angle::*;
+
+use std::f64::consts::PI;
+
+fn close(a: f64, b: f64) -> bool {
+	(a - b).abs() < 1e-12
+}
+
+#[test]
+fn conversion_and_arithmetic() {
+	assert!(close(Rad::from(Deg(90.0)).0, PI / 2.0));
+	assert!(close(Deg::from(Rad(PI)).0, 180.0));
+	assert_eq!(Deg(30.0) + Deg(15.0) - Deg(5.0), Deg(40.0));
+	assert_eq!(Deg(30.0) * 2.0, Deg(60.0));
+	assert_eq!(Deg(90.0) / Deg(30.0), 3.0);
+	assert_eq!(-Rad(1.0), Rad(-1.0));
+	let mut a = Deg(10.0);
+	a += Deg(5.0);
+	a -= Deg(1.0);
+	assert_eq!(a, Deg(14.0));
+	assert_eq!(vec![Deg(10.0), Deg(20.0)].into_iter().sum::<Deg<f64>>(), Deg(30.0));
+	assert!(Deg(10.0) < Deg(20.0));
+	assert_eq!(format!("{}", Deg(45.5)), "45.5°");
+	assert_eq!(format!("{:.2}", Rad(1.0)), "1.00 rad");
+	
+	assert!(close(Deg(30.0).sin(), 0.5));
+	assert!(close(Rad(PI / 3.0).cos(), 0.5));
+	assert!(close(Deg::atan2(1.0, 1.0).0, 45.0));
+	assert!(close(Deg::asin(0.5).0, 30.0));
+	assert!(close(Rad::acos(-1.0).0, PI));
+	let (s, c) = Deg(90.0).sin_cos();
+	assert!(close(s, 1.0) && close(c, 0.0));
+}
+
+#[test]
+fn wrapping() {
+	assert_eq!(Deg(370.0).normalize(), Deg(10.0));
+	assert_eq!(Deg(-10.0).normalize(), Deg(350.0));
+	assert_eq!(Deg(720.0).normalize(), Deg(0.0));
+	assert_eq!(Deg(190.0).normalize_signed(), Deg(-170.0));
+	assert_eq!(Deg(-180.0).normalize_signed(), Deg(180.0));
+	assert!(close(Rad(-PI / 2.0).normalize().0, 1.5 * PI));
+	assert!(close(Rad(3.0 * PI).normalize_signed().0, PI));
+	//a tiny negative angle would otherwise round to a full turn
+	assert_eq!(Rad(-1e-20).normalize(), Rad(0.0));
+	
+	assert_eq!(Deg(350.0).shortest_difference(Deg(10.0)), Deg(20.0));
+	assert_eq!(Deg(10.0).shortest_difference(Deg(350.0)), Deg(-20.0));
+	assert_eq!(Deg(350.0).lerp(Deg(10.0), 0.5), Deg(0.0));
+	assert_eq!(Deg(170.0).lerp(Deg(-170.0), 0.25), Deg(175.0));
+	assert_eq!(Deg(170.0).lerp(Deg(-170.0), 0.75), Deg(-175.0));
+	assert!(close(Rad(0.1).lerp(Rad(2.0 * PI - 0.1), 0.5).0, 0.0));
+}
+
+#[test]
+fn rotation_constructors() {
+	let v = vec2(1.0, 0.0);
+	assert!((Mat2::rotate(Deg(90.0)).apply_to(v) - vec2(0.0, 1.0)).magnitude() < 1e-12);
+	assert!((Mat2::rotate(Rad(PI)).apply_to(v) - vec2(-1.0, 0.0)).magnitude() < 1e-12);
+	let m = Mat3::rotate_z(Deg(180.0));
+	assert!((m.apply_to(vec3(1.0, 0.0, 0.0)) - vec3(-1.0, 0.0, 0.0)).magnitude() < 1e-12);
+	assert_eq!(Mat3::<f64>::rotate_y(Deg(0.0)), Mat3::ident());
+	let q = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Deg(90.0));
+	assert!((q.rotate(vec3(1.0, 0.0, 0.0)) - vec3(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+	assert!(close(Deg::from(angle_between(vec2(1.0, 0.0), vec2(1.0, 1.0))).0, 45.0));
+}
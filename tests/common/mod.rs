@@ -0,0 +1,6 @@
+use math_lib::vec3::*;
+
+//shared by the test files, each pulls it in with mod common
+pub fn close(a: Vec3<f64>, b: Vec3<f64>) -> bool {
+	(a - b).magnitude() < 1e-9
+}
@@ -0,0 +1,98 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::mat2::*;
+use math_lib::complex::*;
+use math_lib::angle::*;
+use math_lib::traits::NiceFmt;
+
+fn close(a: Complex<f64>, b: Complex<f64>) -> bool {
+	(a - b).abs() < 1e-12
+}
+
+#[test]
+fn arithmetic() {
+	let (a, b) = (complex(1.0, 2.0), complex(3.0, -1.0));
+	assert_eq!(a + b, complex(4.0, 1.0));
+	assert_eq!(a - b, complex(-2.0, 3.0));
+	assert_eq!(a * b, complex(5.0, 5.0));
+	assert!(close(a * b / b, a));
+	assert!(close(a * a.inv(), Complex::one()));
+	assert_eq!(a.conj(), complex(1.0, -2.0));
+	assert_eq!(complex(3.0, 4.0).abs(), 5.0);
+	assert_eq!(Complex::<f64>::i() * Complex::i(), complex(-1.0, 0.0));
+	let mut c = a;
+	c *= b;
+	c -= a;
+	assert_eq!(c, complex(4.0, 3.0));
+	assert_eq!(complex(1, 2) * complex(3, -1), complex(5, 5));
+}
+
+#[test]
+fn transcendental() {
+	let pi = std::f64::consts::PI;
+	assert!(close(complex(0.0, pi).exp(), complex(-1.0, 0.0)));
+	let z = complex(0.5, -1.5);
+	assert!(close(z.ln().exp(), z));
+	assert!(close(z.powf(3.0), z * z * z));
+	let (r, theta) = z.to_polar();
+	assert!(close(Complex::from_polar(r, theta), z));
+	assert!((complex(-1.0, 0.0).arg() - pi).abs() < 1e-15);
+	
+	assert_eq!(complex(-4.0, 0.0).sqrt(), complex(0.0, 2.0));
+	assert_eq!(complex(0.0, 2.0).sqrt(), complex(1.0, 1.0));
+	assert_eq!(complex(0.0, 0.0).sqrt(), complex(0.0, 0.0));
+	let s = complex(-3.0, -4.0).sqrt();
+	assert_eq!(s, complex(1.0, -2.0));
+	assert!(close(z.sqrt() * z.sqrt(), z));
+}
+
+#[test]
+fn formatting_and_parsing() {
+	assert_eq!(format!("{}", complex(1.5, -2.0)), "1.5 + -2i");
+	assert_eq!(format!("{:.2}", complex(1.0, 2.0)), "1.00 + 2.00i");
+	assert_eq!(format!("{:e}", complex(1500.0, 2.0)), "1.5e3 + 2e0i");
+	assert_eq!(complex(1.5, 2.0).nice_fmt(5, false), "(1.5 + 2i)");
+	
+	for &s in &["1.5 + -2i", "1.5 - 2i", "(1.5-2i)"] {
+		assert_eq!(s.parse::<Complex<f64>>(), Ok(complex(1.5, -2.0)));
+	}
+	assert_eq!("1.5 - -2e0i".parse::<Complex<f64>>(), Ok(complex(1.5, 2.0)));
+	assert_eq!("1e-3 + 2e+2i".parse::<Complex<f64>>(), Ok(complex(1e-3, 2e2)));
+	assert_eq!("-3".parse::<Complex<f64>>(), Ok(complex(-3.0, 0.0)));
+	assert_eq!("-3i".parse::<Complex<i32>>(), Ok(complex(0, -3)));
+	assert_eq!("i".parse::<Complex<f64>>(), Ok(complex(0.0, 1.0)));
+	assert_eq!("-i".parse::<Complex<f64>>(), Ok(complex(0.0, -1.0)));
+	assert_eq!("2 + i".parse::<Complex<i32>>(), Ok(complex(2, 1)));
+	assert_eq!("(2-i)".parse::<Complex<f64>>(), Ok(complex(2.0, -1.0)));
+	assert_eq!("2 - -i".parse::<Complex<f64>>(), Ok(complex(2.0, 1.0)));
+	assert!("-i".parse::<Complex<u32>>().is_err());
+	let c = complex(0.1, -7.25);
+	assert_eq!(c.to_string().parse::<Complex<f64>>(), Ok(c));
+	assert!("1 + 2j".parse::<Complex<f64>>().is_err());
+	assert!("1 + 2 + 3i".parse::<Complex<f64>>().is_err());
+}
+
+#[test]
+fn rotation2() {
+	let a = Rotation2::from_angle(Rad(0.5));
+	let b = Rotation2::from_angle(Rad(1.2));
+	let v = vec2(2.0, -1.0);
+	assert!(((a * b).rotate(v) - Mat2::rotate(Rad(1.7f64)).apply_to(v)).magnitude() < 1e-12);
+	assert!((a * v - a.to_mat2().apply_to(v)).magnitude() < 1e-12);
+	assert!((a.inverse().rotate(a.rotate(v)) - v).magnitude() < 1e-12);
+	assert!(((a * b).angle().0 - 1.7).abs() < 1e-12);
+	assert!((a.slerp(b, 0.5).angle().0 - 0.85).abs() < 1e-12);
+	//the shorter way round, through pi
+	let (c, d) = (Rotation2::from_angle(Rad(3.0f64)), Rotation2::from_angle(Rad(-3.0)));
+	assert!((c.slerp(d, 0.5).angle().0.abs() - std::f64::consts::PI).abs() < 1e-12);
+	
+	let r = Rotation2::from_rotation_arc(vec2(1.0, 0.0), vec2(0.0, 3.0));
+	assert!((r.angle().0 - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+	let mut drift = Rotation2::<f64>::ident();
+	for _ in 0..1000 {
+		drift = drift * Rotation2::from_angle(Rad(0.001));
+	}
+	assert!((drift.normalise().complex.abs() - 1.0).abs() < 1e-15);
+	assert_eq!(Rotation2::<f64>::default(), Rotation2::ident());
+}
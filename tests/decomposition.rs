@@ -0,0 +1,116 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::vecn::*;
+use math_lib::matn::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+
+fn close<const N: usize>(a: Mat<f64, N, N>, b: Mat<f64, N, N>) -> bool {
+	(0..N).all(|i| (0..N).all(|j| (a[i][j] - b[i][j]).abs() < 1e-9))
+}
+
+fn close_v<const N: usize>(a: VecN<f64, N>, b: VecN<f64, N>) -> bool {
+	(0..N).all(|i| (a[i] - b[i]).abs() < 1e-9)
+}
+
+#[test]
+fn lu() {
+	//needs pivoting, the top left is 0
+	let m = mat3(vec3(0.0, 2.0, 1.0), vec3(1.0, 1.0, 1.0), vec3(2.0, 1.0, 3.0));
+	let lu = m.lu().unwrap();
+	let a = Mat::from(m);
+	assert!(close(lu.p() * a, lu.l() * lu.u()));
+	assert!((lu.det() - m.det()).abs() < 1e-12);
+	assert!(close(lu.inverse(), Mat::from(m.inv())));
+	let x = lu.solve(vecn([3.0, 3.0, 6.0]));
+	assert!(close_v(x, vecn([1.0, 1.0, 1.0])));
+	
+	assert!(mat2(vec2(1.0, 2.0), vec2(2.0, 4.0)).lu().is_none());
+}
+
+#[test]
+fn qr() {
+	let m = mat4(
+		vec4(4.0, -2.0, 1.0, 0.5),
+		vec4(1.0, 3.0, -1.0, 2.0),
+		vec4(0.0, 1.0, 5.0, -1.0),
+		vec4(2.0, 0.0, 1.0, 3.0),
+	);
+	let qr = m.qr();
+	assert!(close(qr.q * qr.r, Mat::from(m)));
+	assert!(close(qr.q.transpose() * qr.q, Mat::ident()));
+	assert!((0..4).all(|i| (0..i).all(|j| qr.r[i][j].abs() < 1e-12)));
+	let b = vecn([1.0, 2.0, 3.0, 4.0]);
+	let x = qr.solve(b).unwrap();
+	assert!(close_v(Mat::from(m) * x, b));
+	assert_eq!(Mat3::<f64>::zero().qr().solve(vecn([1.0, 1.0, 1.0])), None);
+}
+
+#[test]
+fn cholesky() {
+	let m = mat3(vec3(4.0, 12.0, -16.0), vec3(12.0, 37.0, -43.0), vec3(-16.0, -43.0, 98.0));
+	let c = m.cholesky().unwrap();
+	assert!(close(c.l, Mat::from(mat3(vec3(2.0, 0.0, 0.0), vec3(6.0, 1.0, 0.0), vec3(-8.0, 5.0, 3.0)))));
+	let x = c.solve(vecn([1.0, 2.0, 3.0]));
+	assert!(close_v(Mat::from(m) * x, vecn([1.0, 2.0, 3.0])));
+	assert!(mat3(vec3(1.0, 2.0, 0.0), vec3(2.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)).cholesky().is_none());
+}
+
+#[test]
+fn symmetric_eigen() {
+	let m = mat2(vec2(2.0f64, 1.0), vec2(1.0, 2.0));
+	let (l, v) = m.symmetric_eigen();
+	assert!((l.x - 3.0).abs() < 1e-12 && (l.y - 1.0).abs() < 1e-12);
+	let vt = v.transpose();
+	assert!((m.apply_to(vt.x) - vt.x * l.x).magnitude() < 1e-12);
+	assert!((v.det() - 1.0).abs() < 1e-12);
+	
+	let m = mat3(vec3(4.0, 1.0, -2.0), vec3(1.0, 2.0, 0.5), vec3(-2.0, 0.5, 3.0));
+	let (l, v) = m.symmetric_eigen();
+	assert!(l.x >= l.y && l.y >= l.z);
+	assert!(close(Mat::from(v.transpose() * v), Mat::ident()));
+	assert!((v.det() - 1.0).abs() < 1e-9);
+	let vt = v.transpose();
+	for &(c, e) in &[(vt.x, l.x), (vt.y, l.y), (vt.z, l.z)] {
+		assert!((m.apply_to(c) - c * e).magnitude() < 1e-9);
+	}
+	//already diagonal, comes back sorted
+	let (l, _) = mat3(vec3(1.0, 0.0, 0.0), vec3(0.0, 5.0, 0.0), vec3(0.0, 0.0, 3.0)).symmetric_eigen();
+	assert_eq!(l, vec3(5.0, 3.0, 1.0));
+}
+
+#[test]
+fn svd_and_polar() {
+	let diag = |s: Vec3<f64>| mat3(vec3(s.x, 0.0, 0.0), vec3(0.0, s.y, 0.0), vec3(0.0, 0.0, s.z));
+	let ms = [
+		mat3(vec3(1.0, 2.0, 0.0), vec3(0.0, 1.0, 3.0), vec3(4.0, 0.0, 1.0)),
+		//a reflection
+		mat3(vec3(-1.0, 0.5, 0.0), vec3(0.0, 2.0, 0.0), vec3(0.0, 0.0, 1.0)),
+		//rank 1
+		mat3(vec3(1.0, 2.0, 3.0), vec3(2.0, 4.0, 6.0), vec3(1.0, 2.0, 3.0)),
+	];
+	for &m in &ms {
+		let (u, s, v) = m.svd();
+		assert!(s.x >= s.y && s.y >= s.z && s.z >= 0.0);
+		assert!(close(Mat::from(u.transpose() * u), Mat::ident()));
+		assert!(close(Mat::from(v.transpose() * v), Mat::ident()));
+		assert!(close(Mat::from(u * diag(s) * v.transpose()), Mat::from(m)));
+		
+		let (r, p) = m.polar();
+		assert!((r.det() - 1.0).abs() < 1e-9);
+		assert!(close(Mat::from(r * p), Mat::from(m)));
+		assert!(close(Mat::from(p), Mat::from(p.transpose())));
+	}
+	
+	let m = mat2(vec2(3.0, 1.0), vec2(-2.0, 0.5));
+	let (u, s, v) = m.svd();
+	let d = mat2(vec2(s.x, 0.0), vec2(0.0, s.y));
+	assert!(close(Mat::from(u * d * v.transpose()), Mat::from(m)));
+	let (r, p) = m.polar();
+	assert!((r.det() - 1.0).abs() < 1e-9);
+	assert!(close(Mat::from(r * p), Mat::from(m)));
+}
@@ -0,0 +1,58 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec3::*;
+use math_lib::quaternion::*;
+use math_lib::dual_quaternion::*;
+use math_lib::angle::*;
+use common::close;
+
+#[test]
+fn rigid_transforms() {
+	let r = Quaternion::from_axis_angle(vec3(1.0, 2.0, 2.0).normalize(), Rad(0.7));
+	let t = vec3(1.0, -2.0, 0.5);
+	let a = DualQuaternion::from_rotation_translation(r, t);
+	let p = vec3(0.3, 4.0, -1.0);
+	assert!(close(a.transform_point(p), r.rotate(p) + t));
+	assert!(close(a.translation(), t));
+	assert!(close(a.transform_vector(p), r.rotate(p)));
+	
+	let b = DualQuaternion::from_rotation_translation(Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(-1.1)), vec3(0.0, 3.0, 1.0));
+	assert!(close((a * b).transform_point(p), a.transform_point(b.transform_point(p))));
+	assert!(close(a.conjugate().transform_point(a.transform_point(p)), p));
+	assert_eq!(a.dual_conjugate().dual, -a.dual);
+	
+	let m = a.to_mat4();
+	assert!(close(m.transform_point3(p), a.transform_point(p)));
+	assert!(close(DualQuaternion::from_mat4(m).transform_point(p), a.transform_point(p)));
+	
+	//a scaled one comes back as the same transform
+	let n = (a * 3.0).normalise();
+	assert!(close(n.transform_point(p), a.transform_point(p)));
+	assert!((n.real.dot(n.dual)).abs() < 1e-12);
+}
+
+#[test]
+fn interpolation() {
+	let axis = vec3(0.0, 0.0, 1.0);
+	let a = DualQuaternion::ident();
+	let b = DualQuaternion::from_rotation_translation(Quaternion::from_axis_angle(axis, Rad(1.0)), vec3(0.0, 0.0, 2.0));
+	//a screw about z, halfway is half the turn and half the rise
+	let half = a.sclerp(b, 0.5);
+	let expected = DualQuaternion::from_rotation_translation(Quaternion::from_axis_angle(axis, Rad(0.5)), vec3(0.0, 0.0, 1.0));
+	let p = vec3(1.0, 0.0, 0.0);
+	assert!(close(half.transform_point(p), expected.transform_point(p)));
+	assert!(close(a.sclerp(b, 0.0).transform_point(p), p));
+	assert!(close(a.sclerp(b, 1.0).transform_point(p), b.transform_point(p)));
+	//the same rotation from the other hemisphere
+	assert!(close(a.sclerp(-b, 0.5).transform_point(p), expected.transform_point(p)));
+	let c = DualQuaternion::from_translation(vec3(2.0, 0.0, 0.0));
+	assert!(close(a.sclerp(c, 0.25).translation(), vec3(0.5, 0.0, 0.0)));
+	
+	let blended = DualQuaternion::blend(&[(a, 0.5), (-b, 0.5)]).unwrap();
+	assert!((blended.real.norm() - 1.0).abs() < 1e-12);
+	assert!(close(blended.transform_point(vec3(0.0, 0.0, 0.0)), vec3(0.0, 0.0, 1.0)));
+	assert!(close(blended.transform_vector(p), Quaternion::from_axis_angle(axis, Rad(0.5)).rotate(p)));
+	assert_eq!(DualQuaternion::<f64>::blend(&[]), None);
+}
@@ -0,0 +1,91 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::vecn::*;
+use math_lib::matn::*;
+use math_lib::traits::NiceFmt;
+use array_tuple::ArrayTuple;
+
+#[test]
+fn vecn_operators() {
+	let a = vecn([1, 2, 3]);
+	let b = vecn([4, 5, 6]);
+	assert_eq!(a + b, vecn([5, 7, 9]));
+	assert_eq!(a * 2, vecn([2, 4, 6]));
+	assert_eq!(-a, vecn([-1, -2, -3]));
+	assert_eq!(a[2], 3);
+	assert_eq!(vec![a, b].into_iter().sum::<VecN<i32, 3>>(), vecn([5, 7, 9]));
+	assert_eq!(vec![a, b].into_iter().product::<VecN<i32, 3>>(), vecn([4, 10, 18]));
+	assert_eq!(a.into_tuple(), (1, 2, 3));
+	assert_eq!(vecn([3.0, 4.0]).magnitude(), 5.0);
+	assert_eq!(vecn([1.0, 2.0, 3.0, 4.0, 5.0]).max_elem(), 5.0);
+}
+
+#[test]
+fn vecn_named_conversions() {
+	let v: VecN<i32, 3> = vec3(1, 2, 3).into();
+	assert_eq!(v, vecn([1, 2, 3]));
+	assert_eq!(Vec4::from(vecn([1, 2, 3, 4])), vec4(1, 2, 3, 4));
+	let m: Mat<i32, 3, 3> = Mat3::ident().into();
+	assert_eq!(m, Mat::ident());
+	assert_eq!(Mat3::from(m), Mat3::ident());
+}
+
+#[test]
+fn non_square_multiplication() {
+	let a: Mat<i32, 3, 4> = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]].into();
+	let b: Mat<i32, 4, 2> = [[1, 0], [0, 1], [1, 0], [0, 1]].into();
+	let c: Mat<i32, 3, 2> = a * b;
+	assert_eq!(c, [[4, 6], [12, 14], [20, 22]].into());
+	assert_eq!(a * vecn([1, 1, 1, 1]), vecn([10, 26, 42]));
+	assert_eq!(a.transpose().transpose(), a);
+	assert_eq!(a.transpose()[3], vecn([4, 8, 12]));
+	assert_eq!(a * Mat::ident(), a);
+}
+
+#[test]
+fn formatting_and_parsing() {
+	let m: Mat<f64, 2, 3> = [[1.0, 2.0, 3.0], [4.0, 5.5, 6.0]].into();
+	assert_eq!(format!("{}", m), "(1,   2, 3)\n(4, 5.5, 6)");
+	assert_eq!(m.to_string().parse::<Mat<f64, 2, 3>>(), Ok(m));
+	assert_eq!("[1 2 3]".parse::<VecN<i32, 3>>(), Ok(vecn([1, 2, 3])));
+	assert!("1 2".parse::<VecN<i32, 3>>().is_err());
+	assert_eq!(vecn([1.0, 2.5]).nice_fmt(5, false), "(1, 2.5)");
+}
+
+#[test]
+fn named_types_through_generic() {
+	let mut v = vec3(1, 2, 3);
+	v += vec3(1, 1, 1);
+	v %= 3;
+	assert_eq!(v, vec3(2, 0, 1));
+	assert_eq!(vec2(6, 9) / vec2(2, 3) - 1, vec2(2, 2));
+	assert_eq!(-vec4(1, -2, 3, -4), vec4(-1, 2, -3, 4));
+	assert_eq!(vec![vec2(1, 2), vec2(3, 4)].into_iter().sum::<Vec2<i32>>(), vec2(4, 6));
+	assert_eq!(Vec3::<i32>::default(), vec3(0, 0, 0));
+	assert_eq!(format!("{:.1}", vec3(1.0, 2.5, -3.0)), "(1.0, 2.5, -3.0)");
+	assert_eq!(format!("{:e}", vec2(1500.0, 0.5)), "(1.5e3, 5e-1)");
+	assert_eq!(vec2(1.0, 2.5).nice_fmt(5, false), "(1, 2.5)");
+	assert_eq!("(1, 2, 3, 4)".parse::<Vec4<i32>>(), Ok(vec4(1, 2, 3, 4)));
+	assert!("1 2".parse::<Vec3<i32>>().is_err());
+	assert_eq!(vec3(-1.5f64, 2.5, 0.2).floor(), vec3(-2.0, 2.0, 0.0));
+	assert_eq!(vec2(-1i32, 2).is_negative(), vec2(true, false));
+	assert_eq!(vec3(1.9f64, -2.0, 300.0).u8(), vec3(1, 0, 255));
+	assert_eq!(vecn([1.5f64, -2.5]).abs(), vecn([1.5, 2.5]));
+	
+	let m = mat2(vec2(1i32, 2), vec2(3, 4));
+	assert_eq!(m + m - m * 2, Mat2::zero());
+	assert_eq!(m % 3, mat2(vec2(1, 2), vec2(0, 1)));
+	assert_eq!(-m, mat2(vec2(-1, -2), vec2(-3, -4)));
+	assert_eq!(Mat3::<i32>::default(), Mat3::ident());
+	assert_eq!(m.f64() / 2.0, mat2(vec2(0.5, 1.0), vec2(1.5, 2.0)));
+	assert_eq!(format!("{}", m), "(1, 2)\n(3, 4)");
+	let mut d = Mat::<i32, 2, 3>::from([[1, 2, 3], [4, 5, 6]]);
+	d *= 2;
+	d %= 5;
+	assert_eq!(d, [[2, 4, 1], [3, 0, 2]].into());
+}
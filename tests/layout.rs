@@ -0,0 +1,60 @@
+extern crate math_lib;
+
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::layout::*;
+use math_lib::traits::Pod;
+
+#[test]
+fn cols_array() {
+	let m = mat4(
+		vec4(1.0, 2.0, 3.0, 4.0),
+		vec4(5.0, 6.0, 7.0, 8.0),
+		vec4(9.0, 10.0, 11.0, 12.0),
+		vec4(13.0, 14.0, 15.0, 16.0),
+	);
+	let a = m.to_cols_array();
+	assert_eq!(&a[..4], &[1.0, 5.0, 9.0, 13.0]);
+	assert_eq!(Mat4::from_cols_array(a), m);
+	assert_eq!(m.transpose().to_cols_array(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]);
+	
+	let m = mat3(vec3(1, 2, 3), vec3(4, 5, 6), vec3(7, 8, 9));
+	assert_eq!(m.to_cols_array(), [1, 4, 7, 2, 5, 8, 3, 6, 9]);
+	assert_eq!(Mat3::from_cols_array(m.to_cols_array()), m);
+}
+
+#[test]
+fn bytes() {
+	let m = mat4(
+		vec4(1.0f32, 2.0, 3.0, 4.0),
+		vec4(5.0, 6.0, 7.0, 8.0),
+		vec4(9.0, 10.0, 11.0, 12.0),
+		vec4(13.0, 14.0, 15.0, 16.0),
+	);
+	let b = m.as_bytes();
+	assert_eq!(b.len(), 64);
+	assert_eq!(&b[4..8], &2.0f32.to_ne_bytes());
+	assert_eq!(Mat4::<f32>::from_bytes(b), Some(m));
+	assert_eq!(Mat4::<f32>::from_bytes(&b[1..]), None);
+	
+	//unaligned input is fine
+	let mut shifted = vec![0u8];
+	shifted.extend_from_slice(vec3(1u32, 2, 3).as_bytes());
+	assert_eq!(Vec3::<u32>::from_bytes(&shifted[1..]), Some(vec3(1, 2, 3)));
+}
+
+#[test]
+fn std140() {
+	assert_eq!(std::mem::size_of::<Std140Vec3<f32>>(), 16);
+	assert_eq!(std::mem::align_of::<Std140Vec3<f32>>(), 16);
+	assert_eq!(std::mem::size_of::<Std430Mat3<f32>>(), 48);
+	
+	let m = mat3(vec3(1.0f32, 2.0, 3.0), vec3(4.0, 5.0, 6.0), vec3(7.0, 8.0, 9.0));
+	let p = Std140Mat3::from(m);
+	let floats: Vec<f32> = p.as_bytes().chunks(4).map(|c| f32::from_ne_bytes([c[0], c[1], c[2], c[3]])).collect();
+	assert_eq!(floats, vec![1.0, 4.0, 7.0, 0.0, 2.0, 5.0, 8.0, 0.0, 3.0, 6.0, 9.0, 0.0]);
+	assert_eq!(Mat3::from(p), m);
+	assert_eq!(Vec3::from(Std140Vec3::from(vec3(1, 2, 3))), vec3(1, 2, 3));
+}
@@ -0,0 +1,173 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::angle::*;
+
+fn mul4(a: Mat4<i64>, b: Mat4<i64>) -> Mat4<i64> {
+	let (a, b) = (a.into_array(), b.into_array());
+	let e = |r: usize, c: usize| (0..4).map(|n| a[r][n] * b[n][c]).sum();
+	mat4(
+		vec4(e(0,0), e(0,1), e(0,2), e(0,3)),
+		vec4(e(1,0), e(1,1), e(1,2), e(1,3)),
+		vec4(e(2,0), e(2,1), e(2,2), e(2,3)),
+		vec4(e(3,0), e(3,1), e(3,2), e(3,3)),
+	)
+}
+
+fn scaled_ident(d: i64) -> Mat4<i64> {
+	mat4(
+		vec4(d, 0, 0, 0),
+		vec4(0, d, 0, 0),
+		vec4(0, 0, d, 0),
+		vec4(0, 0, 0, d),
+	)
+}
+
+#[test]
+fn mat4_adjoint_is_det_times_inverse() {
+	let matrices = [
+		mat4(vec4(1, 2, 3, 4), vec4(5, 6, 7, 8), vec4(2, 6, 4, 8), vec4(3, 1, 1, 2)),
+		mat4(vec4(2, 0, 0, 0), vec4(0, 3, 0, 0), vec4(0, 0, 5, 0), vec4(0, 0, 0, 7)),
+		mat4(vec4(0, 1, -2, 3), vec4(4, 0, 1, -1), vec4(-3, 2, 0, 5), vec4(1, -1, 2, 0)),
+		mat4(vec4(1, 2, 3, 4), vec4(2, 4, 6, 8), vec4(0, 1, 0, 1), vec4(1, 0, 1, 0)),
+	];
+	for &m in matrices.iter() {
+		let d = m.det();
+		assert_eq!(mul4(m, m.adjoint()), scaled_ident(d));
+		assert_eq!(mul4(m.adjoint(), m), scaled_ident(d));
+	}
+}
+
+#[test]
+fn mat4_minor_removes_row_and_column() {
+	let m = mat4(vec4(1, 2, 3, 4), vec4(5, 6, 7, 8), vec4(9, 10, 11, 12), vec4(13, 14, 15, 16));
+	assert_eq!(m.minor(0, 0), mat3(vec3(6, 7, 8), vec3(10, 11, 12), vec3(14, 15, 16)));
+	assert_eq!(m.minor(2, 1), mat3(vec3(1, 3, 4), vec3(5, 7, 8), vec3(13, 15, 16)));
+	assert_eq!(m.minor(3, 3), mat3(vec3(1, 2, 3), vec3(5, 6, 7), vec3(9, 10, 11)));
+}
+
+#[test]
+fn mat3_cofactor_from_minors() {
+	let m = mat3(vec3(1, 2, 3), vec3(0, 4, 5), vec3(1, 0, 6));
+	assert_eq!(m.minor(1, 2), mat2(vec2(1, 2), vec2(1, 0)));
+	assert_eq!(m.cofactor(), mat3(vec3(24, 5, -4), vec3(-12, 3, 2), vec3(-2, -5, 4)));
+	assert_eq!(m.det(), 22);
+}
+
+#[test]
+fn mat4_inv_matches_adjoint_over_det() {
+	let m = mat4(
+		vec4(4.0, 0.0, 0.0, 1.0),
+		vec4(0.0, 2.0, 0.0, 2.0),
+		vec4(0.0, 0.0, 8.0, 3.0),
+		vec4(0.0, 0.0, 0.0, 1.0),
+	);
+	assert_eq!(m * m.inv(), Mat4::ident());
+}
+
+#[test]
+fn mat2_and_mat3_inv() {
+	let m = mat2(vec2(4.0, 2.0), vec2(1.0, 1.0));
+	assert_eq!(m * m.inv(), Mat2::ident());
+	let m = mat3(vec3(2.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0), vec3(1.0, 0.0, 1.0));
+	assert_eq!(m * m.inv(), Mat3::ident());
+}
+
+#[test]
+fn try_inv_rejects_singular_matrices() {
+	let singular = mat4(vec4(1, 2, 3, 4), vec4(2, 4, 6, 8), vec4(0, 1, 0, 1), vec4(1, 0, 1, 0));
+	assert_eq!(singular.try_inv(), None);
+	assert_eq!(mat2(vec2(1.0, 2.0), vec2(2.0, 4.0)).try_inv(), None);
+	assert_eq!(mat3(vec3(1.0, 0.0, 0.0), vec3(0.0, 1e-9, 0.0), vec3(0.0, 0.0, 1.0)).try_inv_eps(1e-6), None);
+	assert_eq!(mat2(vec2(f64::NAN, 0.0), vec2(0.0, 1.0)).try_inv_eps(1e-6), None);
+	let m = mat3(vec3(2.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0), vec3(0.0, 0.0, 8.0));
+	assert_eq!(m.try_inv(), Some(m.inv()));
+	assert_eq!(m.inv_with_det(), (m.inv(), 64.0));
+}
+
+#[test]
+fn mat4_transform_constructors() {
+	let t = Mat4::translation(vec3(1.0, 2.0, 3.0));
+	assert_eq!(t.transform_point3(vec3(1.0, 1.0, 1.0)), vec3(2.0, 3.0, 4.0));
+	assert_eq!(t.transform_vector3(vec3(1.0, 1.0, 1.0)), vec3(1.0, 1.0, 1.0));
+	let s = Mat4::scale(vec3(2.0, 3.0, 4.0));
+	assert_eq!((t * s).transform_point3(vec3(1.0, 1.0, 1.0)), vec3(3.0, 5.0, 7.0));
+	let r = Mat4::from_rotation(Mat3::rotate_z(Rad(std::f64::consts::FRAC_PI_2)));
+	let p = r.transform_point3(vec3(1.0, 0.0, 0.0));
+	assert!((p - vec3(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+	
+	let view = Mat4::look_at_rh(vec3(0.0, 0.0, 5.0), vec3(0.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0));
+	assert_eq!(view.transform_point3(vec3(0.0, 0.0, 5.0)), vec3(0.0, 0.0, 0.0));
+	assert_eq!(view.transform_point3(vec3(0.0, 0.0, 0.0)), vec3(0.0, 0.0, -5.0));
+	let view = Mat4::look_at_lh(vec3(0.0, 0.0, 5.0), vec3(0.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0));
+	assert_eq!(view.transform_point3(vec3(0.0, 0.0, 0.0)), vec3(0.0, 0.0, 5.0));
+}
+
+#[test]
+fn mat4_projections_map_near_and_far_planes() {
+	let close = |a: Vec3<f64>, b: Vec3<f64>| (a - b).magnitude() < 1e-9;
+	let fov = std::f64::consts::FRAC_PI_2;
+	for &(depth, lo) in [(DepthRange::NegOneToOne, -1.0), (DepthRange::ZeroToOne, 0.0)].iter() {
+		let p = Mat4::perspective(fov, 2.0, 1.0, 100.0, depth);
+		assert!(close(p.project_point3(vec3(2.0, 1.0, -1.0)), vec3(1.0, 1.0, lo)));
+		assert!(close(p.project_point3(vec3(0.0, 0.0, -100.0)), vec3(0.0, 0.0, 1.0)));
+		assert!(close(p.unproject(vec3(1.0, 1.0, lo)), vec3(2.0, 1.0, -1.0)));
+		
+		let r = Mat4::perspective_reversed(fov, 2.0, 1.0, 100.0, depth);
+		assert!(close(r.project_point3(vec3(0.0, 0.0, -1.0)), vec3(0.0, 0.0, 1.0)));
+		assert!(close(r.project_point3(vec3(0.0, 0.0, -100.0)), vec3(0.0, 0.0, lo)));
+		
+		let i = Mat4::perspective_infinite(fov, 1.0, 1.0, depth);
+		assert!(close(i.project_point3(vec3(0.0, 0.0, -1.0)), vec3(0.0, 0.0, lo)));
+		assert!(i.project_point3(vec3(0.0, 0.0, -1e12)).z < 1.0);
+		let i = Mat4::perspective_infinite_reversed(fov, 1.0, 1.0, depth);
+		assert!(close(i.project_point3(vec3(0.0, 0.0, -1.0)), vec3(0.0, 0.0, 1.0)));
+		
+		let o = Mat4::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0, depth);
+		assert!(close(o.project_point3(vec3(2.0, -1.0, -1.0)), vec3(1.0, -1.0, lo)));
+		assert!(close(o.project_point3(vec3(-2.0, 1.0, -11.0)), vec3(-1.0, 1.0, 1.0)));
+		
+		let f = Mat4::frustum(-1.0, 3.0, -1.0, 1.0, 1.0, 10.0, depth);
+		assert!(close(f.project_point3(vec3(3.0, 1.0, -1.0)), vec3(1.0, 1.0, lo)));
+		assert!(close(f.unproject(vec3(-1.0, -1.0, 1.0)), vec3(-10.0, -10.0, -10.0)));
+	}
+}
+
+#[test]
+fn matrix_operator_parity() {
+	let m = mat3(vec3(1, 2, 3), vec3(4, 5, 6), vec3(7, 8, 9));
+	assert_eq!(m * 2, mat3(vec3(2, 4, 6), vec3(8, 10, 12), vec3(14, 16, 18)));
+	assert_eq!(m * 2 / 2, m);
+	assert_eq!(m % 4, mat3(vec3(1, 2, 3), vec3(0, 1, 2), vec3(3, 0, 1)));
+	assert_eq!(m[1], vec3(4, 5, 6));
+	assert_eq!(m[2].z, 9);
+	assert_eq!(Mat3::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]), m);
+	assert_eq!(Mat3::from(((1, 2, 3), (4, 5, 6), (7, 8, 9))), m);
+	assert_eq!(vec![m, m].into_iter().sum::<Mat3<i32>>(), m * 2);
+	
+	let a = mat2(vec2(1.0, 2.0), vec2(3.0, 4.0));
+	let b = mat2(vec2(0.0, 1.0), vec2(1.0, 0.0));
+	let mut c = a;
+	c *= b;
+	assert_eq!(c, a * b);
+	assert_eq!(vec![a, b].into_iter().product::<Mat2<f64>>(), a * b);
+	assert_eq!(vec2(1.0, 1.0) * a, vec2(4.0, 6.0));
+	assert_eq!(vec2(1.0, 1.0) * a, a.transpose() * vec2(1.0, 1.0));
+}
+
+#[test]
+fn matrix_formatting() {
+	let m = mat2(vec2(1.0, -20.5), vec2(300.0, 4.0));
+	assert_eq!(format!("{}", m), "(  1, -20.5)\n(300,     4)");
+	assert_eq!(format!("{:.1}", m), "(  1.0, -20.5)\n(300.0,   4.0)");
+	assert_eq!(m.to_string().parse::<Mat2<f64>>(), Ok(m));
+	assert_eq!("1 2 3 4 5 6 7 8 9".parse::<Mat3<i32>>(), Ok(mat3(vec3(1, 2, 3), vec3(4, 5, 6), vec3(7, 8, 9))));
+	assert!("1 2 3".parse::<Mat2<i32>>().is_err());
+	let m = mat2(vec2(1.5, 2.0), vec2(3.0, 4.25));
+	assert_eq!(math_lib::traits::NiceFmt::nice_fmt(&m, 5, false), "(1.5,    2)\n(  3, 4.25)");
+}
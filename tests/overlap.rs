@@ -0,0 +1,78 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec3::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::aabb::*;
+use math_lib::shapes::*;
+use math_lib::overlap::*;
+use common::close;
+
+fn rot_z(a: f64) -> Mat3<f64> {
+	let (s, c) = a.sin_cos();
+	mat3(vec3(c, -s, 0.0), vec3(s, c, 0.0), vec3(0.0, 0.0, 1.0))
+}
+
+#[test]
+fn spheres_and_boxes() {
+	let c = sphere_sphere(sphere(Vec3::zero(), 1.0), sphere(vec3(1.5, 0.0, 0.0), 1.0)).unwrap();
+	assert_eq!((c.normal, c.depth), (vec3(1.0, 0.0, 0.0), 0.5));
+	assert_eq!(sphere_sphere(sphere(Vec3::zero(), 1.0), sphere(vec3(0.0, 3.0, 0.0), 1.0)), None);
+	assert_eq!(sphere_sphere(sphere(Vec3::zero(), 1.0), sphere(Vec3::zero(), 1.0)).unwrap().depth, 2.0);
+	
+	let b = aabb3(vec3(0.0, 0.0, 0.0), vec3(2.0, 2.0, 2.0));
+	let c = sphere_aabb(sphere(vec3(-0.5, 1.0, 1.0), 1.0), b).unwrap();
+	assert_eq!((c.normal, c.depth), (vec3(1.0, 0.0, 0.0), 0.5));
+	assert_eq!(sphere_aabb(sphere(vec3(-2.0, 1.0, 1.0), 1.0), b), None);
+	//center inside, nearest the top face
+	let c = sphere_aabb(sphere(vec3(1.0, 1.75, 1.0), 0.5), b).unwrap();
+	assert_eq!((c.normal, c.depth), (vec3(0.0, -1.0, 0.0), 0.75));
+	
+	let c = aabb_aabb(b, aabb3(vec3(1.0, 1.5, -1.0), vec3(3.0, 4.0, 3.0))).unwrap();
+	assert_eq!((c.normal, c.depth), (vec3(0.0, 1.0, 0.0), 0.5));
+	assert_eq!(aabb_aabb(b, aabb3(vec3(2.5, 0.0, 0.0), vec3(3.0, 1.0, 1.0))), None);
+}
+
+#[test]
+fn separating_axes() {
+	let a = obb3(Vec3::zero(), Mat3::ident(), vec3(1.0, 1.0, 1.0));
+	let b = obb3(vec3(2.2, 0.0, 0.0), rot_z(std::f64::consts::FRAC_PI_4), vec3(1.0, 1.0, 1.0));
+	//the corner of the turned box reaches sqrt 2 back towards a
+	let c = obb_obb(a, b).unwrap();
+	assert!(close(c.normal, vec3(1.0, 0.0, 0.0)));
+	assert!((c.depth - (2.0f64.sqrt() - 1.2)).abs() < 1e-9);
+	assert_eq!(obb_obb(a, obb3(vec3(2.5, 0.0, 0.0), rot_z(std::f64::consts::FRAC_PI_4), vec3(1.0, 1.0, 1.0))), None);
+	
+	let t = triangle3(vec3(-1.0, 0.5, -1.0), vec3(3.0, 0.5, -1.0), vec3(-1.0, 0.5, 3.0));
+	let c = triangle_aabb(t, aabb3(Vec3::zero(), Vec3::one())).unwrap();
+	assert_eq!((c.normal, c.depth), (vec3(0.0, 1.0, 0.0), 0.5));
+	assert_eq!(triangle_aabb(t, aabb3(vec3(0.0, 0.5, 0.0), Vec3::one())).unwrap().depth, 0.0);
+	let far = triangle3(vec3(2.0, 0.0, 0.0), vec3(0.0, 2.0, 0.0), vec3(0.0, 0.0, 2.0));
+	assert!(triangle_aabb(far, aabb3(Vec3::zero(), vec3(0.5, 0.5, 0.5))).is_none());
+}
+
+#[test]
+fn frustum() {
+	let view = Mat4::look_at_rh(Vec3::zero(), vec3(0.0, 0.0, -1.0), vec3(0.0, 1.0, 0.0));
+	for &depth in &[DepthRange::NegOneToOne, DepthRange::ZeroToOne] {
+		let f = Frustum::from_mat4(Mat4::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 100.0, depth) * view, depth);
+		assert!((f.planes[4].signed_distance(vec3(0.0, 0.0, -3.0)) - 2.0).abs() < 1e-9);
+		assert!(f.contains_point(vec3(0.0, 0.0, -50.0)));
+		assert!(!f.contains_point(vec3(0.0, 0.0, -101.0)));
+		assert!(!f.contains_point(vec3(11.0, 0.0, -10.0)));
+		
+		assert_eq!(f.test_sphere(sphere(vec3(0.0, 0.0, -10.0), 1.0)), Containment::Inside);
+		assert_eq!(f.test_sphere(sphere(vec3(10.0, 0.0, -10.0), 1.0)), Containment::Intersects);
+		assert_eq!(f.test_sphere(sphere(vec3(0.0, 0.0, 5.0), 1.0)), Containment::Outside);
+		
+		assert_eq!(f.test_aabb(aabb3(vec3(-1.0, -1.0, -11.0), vec3(1.0, 1.0, -9.0))), Containment::Inside);
+		assert_eq!(f.test_aabb(aabb3(vec3(-1.0, -1.0, -101.0), vec3(1.0, 1.0, -99.0))), Containment::Intersects);
+		assert_eq!(f.test_aabb(aabb3(vec3(20.0, -1.0, -11.0), vec3(22.0, 1.0, -9.0))), Containment::Outside);
+	}
+	
+	//infinite projections have no far plane
+	let f = Frustum::from_mat4(Mat4::perspective_infinite(std::f64::consts::FRAC_PI_2, 1.0, 1.0, DepthRange::ZeroToOne), DepthRange::ZeroToOne);
+	assert!(f.contains_point(vec3(0.0, 0.0, -1e12)));
+}
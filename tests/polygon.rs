@@ -0,0 +1,76 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::polygon::*;
+
+#[test]
+fn orientation() {
+	assert_eq!(orient2d(vec2(0i64, 0), vec2(1, 0), vec2(0, 1)), Orientation::CounterClockwise);
+	assert_eq!(orient2d(vec2(0i64, 0), vec2(0, 1), vec2(1, 0)), Orientation::Clockwise);
+	//these products overflow i64
+	let big = i64::MAX / 2;
+	assert_eq!(orient2d(vec2(-big, -big), vec2(big, big), vec2(big - 1, big - 1)), Orientation::Collinear);
+	assert_eq!(orient2d(vec2(-big, -big), vec2(big, big), vec2(big - 1, big)), Orientation::CounterClockwise);
+	//and at the full range these overflow i128
+	let (lo, hi) = (i64::MIN, i64::MAX);
+	assert_eq!(orient2d(vec2(lo, lo), vec2(hi, lo), vec2(hi, hi)), Orientation::CounterClockwise);
+	assert_eq!(orient2d(vec2(lo, lo), vec2(hi, hi), vec2(hi, lo)), Orientation::Clockwise);
+	assert_eq!(orient2d(vec2(lo, lo), vec2(hi, hi), vec2(0, 0)), Orientation::Collinear);
+	assert_eq!(orient2d(vec2(hi, lo), vec2(lo, hi), vec2(lo, lo)), Orientation::CounterClockwise);
+	
+	//nearly collinear points where the naive determinant gets the sign wrong or zero
+	let (a, b) = (vec2(0.5, 0.5), vec2(12.0, 12.0));
+	let c = vec2(24.0, 24.0);
+	assert_eq!(orient2d(a, b, c), Orientation::Collinear);
+	let p = vec2(0.5 + f64::EPSILON * 0.5, 0.5);
+	assert_eq!(orient2d(p, b, c), Orientation::Clockwise);
+	let p = vec2(0.5, 0.5 + f64::EPSILON * 0.5);
+	assert_eq!(orient2d(p, b, c), Orientation::CounterClockwise);
+	assert_eq!(orient2d(vec2(0.0f32, 0.0), vec2(1.0, 1.0), vec2(3.0, 3.0)), Orientation::Collinear);
+	
+	assert_eq!(perp_dot(vec2(1, 0), vec2(0, 1)), 1);
+	assert_eq!(vec2(1, 2).perp(), vec2(-2, 1));
+}
+
+#[test]
+fn polygon() {
+	let square = polygon2(vec![vec2(0, 0), vec2(4, 0), vec2(4, 4), vec2(0, 4)]);
+	assert_eq!(square.twice_signed_area(), 32);
+	assert_eq!(square.winding(), Orientation::CounterClockwise);
+	assert_eq!(square.centroid(), Some(vec2(2, 2)));
+	assert!(square.contains_point(vec2(1, 3)));
+	assert!(square.contains_point(vec2(4, 2)));
+	assert!(!square.contains_point(vec2(5, 2)));
+	
+	let l = polygon2(vec![vec2(0.0f64, 0.0), vec2(0.0, 2.0), vec2(1.0, 2.0), vec2(1.0, 1.0), vec2(2.0, 1.0), vec2(2.0, 0.0)]);
+	assert_eq!(l.winding(), Orientation::Clockwise);
+	assert_eq!(l.signed_area(), -3.0);
+	assert_eq!(l.area(), 3.0);
+	let c = l.centroid().unwrap();
+	assert!((c.x - 5.0 / 6.0).abs() < 1e-12 && (c.y - 5.0 / 6.0).abs() < 1e-12);
+	assert!(l.contains_point(vec2(0.5, 1.5)));
+	assert!(!l.contains_point(vec2(1.5, 1.5)));
+	assert_eq!(polygon2(vec![vec2(0.0, 0.0), vec2(1.0, 1.0), vec2(2.0, 2.0)]).centroid(), None);
+}
+
+#[test]
+fn convex_hull() {
+	let points = [vec2(0, 0), vec2(2, 0), vec2(1, 1), vec2(2, 2), vec2(0, 2), vec2(1, 0), vec2(2, 2), vec2(1, 2)];
+	assert_eq!(Polygon2::convex_hull(&points).points, vec![vec2(0, 0), vec2(2, 0), vec2(2, 2), vec2(0, 2)]);
+	assert_eq!(Polygon2::convex_hull(&[vec2(0, 0), vec2(1, 1), vec2(2, 2)]).points, vec![vec2(0, 0), vec2(2, 2)]);
+	assert_eq!(Polygon2::convex_hull(&[vec2(1.0, 1.0), vec2(f64::NAN, 0.0)]).points, vec![vec2(1.0, 1.0)]);
+}
+
+#[test]
+fn segments() {
+	let s = segment2(vec2(0.0, 0.0), vec2(4.0, 4.0));
+	assert_eq!(s.intersection(segment2(vec2(0.0, 4.0), vec2(4.0, 0.0))), SegmentIntersection::Point(vec2(2.0, 2.0)));
+	assert_eq!(s.intersection(segment2(vec2(4.0, 4.0), vec2(5.0, 0.0))), SegmentIntersection::Point(vec2(4.0, 4.0)));
+	assert_eq!(s.intersection(segment2(vec2(5.0, 5.0), vec2(2.0, 2.0))), SegmentIntersection::Overlap(vec2(2.0, 2.0), vec2(4.0, 4.0)));
+	assert_eq!(s.intersection(segment2(vec2(1.0, 0.0), vec2(5.0, 4.0))), SegmentIntersection::None);
+	assert_eq!(s.intersection(segment2(vec2(0.0, 1.0), vec2(0.0, 5.0))), SegmentIntersection::None);
+	assert!(segment2(vec2(0i64, 0), vec2(2, 0)).intersects(segment2(vec2(1, 0), vec2(1, 5))));
+	let cross = segment2(vec2(0i64, 0), vec2(10, 10)).intersection(segment2(vec2(0, 10), vec2(10, 0)));
+	assert_eq!(cross, SegmentIntersection::Point(vec2(5, 5)));
+	assert_eq!(segment2(vec2(0i64, 0), vec2(3, 0)).intersection(segment2(vec2(2, -1), vec2(2, 1))), SegmentIntersection::Point(vec2(2, 0)));
+}
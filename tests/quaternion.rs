@@ -0,0 +1,178 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec3::*;
+use math_lib::quaternion::*;
+use math_lib::angle::*;
+use common::close;
+
+use std::f64::consts::PI;
+
+fn close_q(a: Quaternion<f64>, b: Quaternion<f64>) -> bool {
+	let d = quaternion(a.r - b.r, a.i - b.i, a.j - b.j, a.k - b.k);
+	d.norm() < 1e-9
+}
+
+#[test]
+fn conjugate_and_inverse() {
+	let q = quaternion(1.0, 2.0, -3.0, 0.5);
+	assert_eq!(q.conjugate(), quaternion(1.0, -2.0, 3.0, -0.5));
+	assert_eq!(q.norm_squared(), 14.25);
+	assert!(close_q(q * q.inverse(), Quaternion::ident()));
+	assert!(close_q(q.inverse() * q, Quaternion::ident()));
+	let u = q.normalise();
+	assert!(close_q(u.inverse(), u.conjugate()));
+}
+
+#[test]
+fn rotate_matches_rot_mat() {
+	let q = quaternion(0.3, -0.2, 0.9, 0.4).normalise();
+	let v = vec3(1.0, -2.0, 0.5);
+	assert!(close(q.rotate(v), q.rot_mat() * v));
+	assert!(close(q * v, q.rot_mat() * v));
+	assert!(close(q.conjugate() * (q * v), v));
+	
+	let half = std::f64::consts::FRAC_PI_4;
+	let z90 = quaternion(half.cos(), 0.0, 0.0, half.sin());
+	assert!(close(z90 * vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0)));
+	assert!(close((z90 * z90) * vec3(1.0, 0.0, 0.0), vec3(-1.0, 0.0, 0.0)));
+}
+
+fn euler_vec3((a, b, c): (Rad<f64>, Rad<f64>, Rad<f64>)) -> Vec3<f64> {
+	vec3(a.0, b.0, c.0)
+}
+
+const ORDERS: [EulerOrder; 12] = [
+	EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX,
+	EulerOrder::XYX, EulerOrder::XZX, EulerOrder::YXY, EulerOrder::YZY, EulerOrder::ZXZ, EulerOrder::ZYZ,
+];
+
+fn same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) -> bool {
+	close_q(a, b) || close_q(a, -b)
+}
+
+#[test]
+fn euler_round_trip() {
+	use math_lib::mat3::*;
+	let angles = [
+		vec3(0.1, 0.2, 0.3),
+		vec3(-2.5, 1.1, 3.0),
+		vec3(1.0, -0.7, -1.9),
+		vec3(0.4, 0.0, 0.9),
+		vec3(0.4, std::f64::consts::FRAC_PI_2, 0.9),
+		vec3(0.4, std::f64::consts::PI, 0.9),
+	];
+	for &order in ORDERS.iter() {
+		for &a in angles.iter() {
+			let q = Quaternion::from_euler(order, Rad(a.x), Rad(a.y), Rad(a.z));
+			let e = q.to_euler(order);
+			assert!(same_rotation(Quaternion::from_euler(order, e.0, e.1, e.2), q), "{:?} {:?} {:?}", order, a, e);
+		}
+	}
+	let q = Quaternion::from_euler(EulerOrder::XYZ, Rad(0.1), Rad(0.2), Rad(0.3));
+	assert!(close(euler_vec3(q.to_euler(EulerOrder::XYZ)), vec3(0.1, 0.2, 0.3)));
+	let m = Mat3::rotate_x(Rad(0.1)) * Mat3::rotate_y(Rad(0.2)) * Mat3::rotate_z(Rad(0.3));
+	assert!(same_rotation(Quaternion::from_rot_mat(m), q));
+	let q = Quaternion::from_euler(EulerOrder::ZXZ, Rad(-0.5), Rad(2.0), Rad(1.5));
+	assert!(close(euler_vec3(q.to_euler(EulerOrder::ZXZ)), vec3(-0.5, 2.0, 1.5)));
+	
+	//degrees and radians mix, the unit is part of each angle
+	let q = Quaternion::from_euler(EulerOrder::ZYX, Deg(90.0), Rad(0.0), Deg(-45.0));
+	assert!(same_rotation(q, Quaternion::from_euler(EulerOrder::ZYX, Rad(PI / 2.0), Rad(0.0), Rad(-PI / 4.0))));
+	assert!(close(q * vec3(0.0, 1.0, 0.0), vec3(-0.5f64.sqrt(), 0.0, -0.5f64.sqrt())));
+	let (z, y, x) = q.to_euler(EulerOrder::ZYX);
+	assert!((Deg::from(z).0 - 90.0).abs() < 1e-9 && y.0.abs() < 1e-9 && (Deg::from(x).0 + 45.0).abs() < 1e-9);
+}
+
+#[test]
+fn axis_angle_and_rotation_vector() {
+	let q = Quaternion::from_axis_angle(vec3(0.0, 0.0, 2.0), Rad(std::f64::consts::FRAC_PI_2));
+	assert!(close(q * vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0)));
+	let v = vec3(0.3, -1.2, 0.8);
+	let q = Quaternion::from_rotation_vector(v);
+	assert!(same_rotation(q, Quaternion::from_axis_angle(v, Rad(v.magnitude()))));
+	assert!(close(q.to_rotation_vector(), v));
+	assert!(close((-q).to_rotation_vector(), v));
+	assert_eq!(Quaternion::from_rotation_vector(vec3(0.0, 0.0, 0.0)), Quaternion::ident());
+}
+
+#[test]
+fn rot_mat_round_trip() {
+	let qs = [
+		quaternion(1.0, 0.0, 0.0, 0.0),
+		quaternion(0.0, 1.0, 0.0, 0.0),
+		quaternion(0.0, 0.0, 1.0, 0.0),
+		quaternion(0.0, 0.0, 0.0, 1.0),
+		quaternion(0.1, 0.9, -0.3, 0.2).normalise(),
+		quaternion(-0.2, 0.1, 0.7, 0.6).normalise(),
+	];
+	for &q in qs.iter() {
+		assert!(same_rotation(Quaternion::from_rot_mat(q.rot_mat()), q));
+	}
+}
+
+#[test]
+fn rotation_arc() {
+	let pairs = [
+		(vec3(1.0, 0.0, 0.0), vec3(0.0, 3.0, 0.0)),
+		(vec3(1.0, 2.0, 3.0), vec3(-1.0, 0.5, 2.0)),
+		(vec3(1.0, 1.0, 1.0), vec3(-1.0, -1.0, -1.0)),
+		(vec3(0.0, 0.0, 1.0), vec3(0.0, 0.0, -1.0)),
+		(vec3(0.0, 2.0, 0.0), vec3(0.0, 2.0, 0.0)),
+	];
+	for &(a, b) in pairs.iter() {
+		let q: Quaternion<f64> = Quaternion::from_rotation_arc(a, b);
+		assert!((q.norm() - 1.0).abs() < 1e-12);
+		assert!(close(q * a.normalize(), b.normalize()));
+	}
+}
+
+#[test]
+fn slerp_and_nlerp() {
+	let a = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(0.2));
+	let b = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(1.4));
+	let mid = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(0.8));
+	assert!(close_q(a.slerp(b, 0.0), a));
+	assert!(close_q(a.slerp(b, 1.0), b));
+	assert!(close_q(a.slerp(b, 0.5), mid));
+	assert!(close_q(a.slerp(b, 0.25), Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(0.5))));
+	assert!(same_rotation(a.slerp(-b, 0.5), mid));
+	assert!(close_q(a.nlerp(b, 0.5), mid));
+	assert!(close_q(a.slerp(a, 0.3), a));
+	let c = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(0.2 + 1e-9));
+	assert!(close_q(a.slerp(c, 0.5), a));
+}
+
+#[test]
+fn exp_ln_pow() {
+	let q = quaternion(0.5, -0.3, 0.8, 0.1);
+	assert!(close_q(q.ln().exp(), q));
+	let u = Quaternion::from_axis_angle(vec3(1.0, 2.0, 3.0), Rad(1.2));
+	assert!(close_q(u.pow(0.5) * u.pow(0.5), u));
+	assert!(close_q(u.pow(0.25), Quaternion::from_axis_angle(vec3(1.0, 2.0, 3.0), Rad(0.3))));
+	assert!(close_q(Quaternion::<f64>::ident().ln(), quaternion(0.0, 0.0, 0.0, 0.0)));
+}
+
+#[test]
+fn squad_interpolates_keyframes() {
+	let keys: Vec<Quaternion<f64>> = [0.0, 0.5, 1.3, 1.6]
+		.iter().map(|&a| Quaternion::from_axis_angle(vec3(0.2, 1.0, 0.1), Rad(a))).collect();
+	let s1 = Quaternion::squad_control(keys[0], keys[1], keys[2]);
+	let s2 = Quaternion::squad_control(keys[1], keys[2], keys[3]);
+	assert!(close_q(keys[1].squad(s1, s2, keys[2], 0.0), keys[1]));
+	assert!(close_q(keys[1].squad(s1, s2, keys[2], 1.0), keys[2]));
+	let m = keys[1].squad(s1, s2, keys[2], 0.5);
+	assert!((m.norm() - 1.0).abs() < 1e-9);
+	//all keys share an axis, so squad stays on it
+	assert!(close(cross(m.vector(), vec3(0.2, 1.0, 0.1)), vec3(0.0, 0.0, 0.0)));
+}
+
+#[test]
+fn integrate_angular_velocity() {
+	let mut q = Quaternion::ident();
+	for _ in 0..100 {
+		q = q.integrate(vec3(0.0, 0.0, std::f64::consts::PI), 0.01);
+	}
+	assert!(close(q * vec3(1.0, 0.0, 0.0), vec3(-1.0, 0.0, 0.0)));
+}
@@ -0,0 +1,79 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec3::*;
+use math_lib::mat3::*;
+use math_lib::aabb::*;
+use math_lib::shapes::*;
+use math_lib::ray::*;
+use common::close;
+
+#[test]
+fn ray_sphere() {
+	let s = sphere(vec3(0.0, 0.0, 5.0), 1.0);
+	let hit = ray3(Vec3::zero(), vec3(0.0, 0.0, 1.0)).intersect_sphere(s).unwrap();
+	assert_eq!(hit.distance, 4.0);
+	assert_eq!(hit.normal, vec3(0.0, 0.0, -1.0));
+	
+	//from inside the sphere is left on the far side, the normal still faces the ray
+	let hit = ray3(vec3(0.0, 0.0, 5.0), vec3(0.0, 0.0, 2.0)).intersect_sphere(s).unwrap();
+	assert_eq!(hit.distance, 0.5);
+	assert_eq!(hit.normal, vec3(0.0, 0.0, -1.0));
+	
+	assert_eq!(ray3(Vec3::zero(), vec3(0.0, 0.0, -1.0)).intersect_sphere(s), None);
+	assert_eq!(ray3(Vec3::zero(), vec3(0.0, 1.0, 0.0)).intersect_sphere(s), None);
+	assert_eq!(ray3(Vec3::zero(), Vec3::zero()).intersect_sphere(s), None);
+	assert_eq!(ray3(Vec3::zero(), vec3(0.0, f64::NAN, 1.0)).intersect_sphere(s), None);
+}
+
+#[test]
+fn ray_plane() {
+	let p = plane(vec3(0.0, 1.0, 0.0), -2.0);
+	let hit = ray3(Vec3::zero(), vec3(1.0, 1.0, 0.0)).intersect_plane(p).unwrap();
+	assert_eq!(hit.distance, 2.0);
+	assert_eq!(hit.normal, vec3(0.0, -1.0, 0.0));
+	let hit = ray3(vec3(0.0, 5.0, 0.0), vec3(0.0, -1.0, 0.0)).intersect_plane(p).unwrap();
+	assert_eq!((hit.distance, hit.normal), (3.0, vec3(0.0, 1.0, 0.0)));
+	
+	assert_eq!(ray3(Vec3::zero(), vec3(1.0, 0.0, 0.0)).intersect_plane(p), None);
+	assert_eq!(ray3(Vec3::zero(), vec3(0.0, -1.0, 0.0)).intersect_plane(p), None);
+	assert_eq!(ray3(Vec3::zero(), vec3(0.0, 1.0, 0.0)).intersect_plane(plane(vec3(0.0, 1.0, 0.0), f64::NAN)), None);
+}
+
+#[test]
+fn ray_boxes() {
+	let b = aabb3(vec3(1.0, -1.0, -1.0), vec3(3.0, 1.0, 1.0));
+	let hit = ray3(Vec3::zero(), vec3(1.0, 0.0, 0.0)).intersect_aabb(b).unwrap();
+	assert_eq!((hit.distance, hit.normal), (1.0, vec3(-1.0, 0.0, 0.0)));
+	let hit = ray3(vec3(2.0, 0.0, 0.0), vec3(0.0, 0.0, -1.0)).intersect_aabb(b).unwrap();
+	assert_eq!((hit.distance, hit.normal), (1.0, vec3(0.0, 0.0, 1.0)));
+	
+	//parallel to a face, touching and missing
+	assert!(ray3(vec3(0.0, 1.0, 0.0), vec3(1.0, 0.0, 0.0)).intersect_aabb(b).is_some());
+	assert_eq!(ray3(vec3(0.0, 2.0, 0.0), vec3(1.0, 0.0, 0.0)).intersect_aabb(b), None);
+	assert_eq!(ray3(Vec3::zero(), vec3(-1.0, 0.0, 0.0)).intersect_aabb(b), None);
+	assert_eq!(ray3(Vec3::zero(), Vec3::zero()).intersect_aabb(b), None);
+	assert_eq!(ray3(vec3(f64::NAN, 0.0, 0.0), vec3(1.0, 0.0, 0.0)).intersect_aabb(b), None);
+	
+	//the box above turned a quarter about z, so it sits along +y
+	let o = obb3(vec3(0.0, 2.0, 0.0), mat3(vec3(0.0, -1.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0)), vec3(1.0, 1.0, 1.0));
+	let hit = ray3(Vec3::zero(), vec3(0.0, 1.0, 0.0)).intersect_obb(o).unwrap();
+	assert_eq!(hit.distance, 1.0);
+	assert!(close(hit.normal, vec3(0.0, -1.0, 0.0)));
+	assert_eq!(ray3(Vec3::zero(), vec3(1.0, 0.0, 0.0)).intersect_obb(o), None);
+}
+
+#[test]
+fn ray_triangle() {
+	let t = triangle3(vec3(0.0, 0.0, 2.0), vec3(1.0, 0.0, 2.0), vec3(0.0, 1.0, 2.0));
+	let hit = ray3(vec3(0.25, 0.25, 0.0), vec3(0.0, 0.0, 1.0)).intersect_triangle(t).unwrap();
+	assert_eq!((hit.distance, hit.normal), (2.0, vec3(0.0, 0.0, -1.0)));
+	let hit = ray3(vec3(0.25, 0.25, 4.0), vec3(0.0, 0.0, -1.0)).intersect_triangle(t).unwrap();
+	assert_eq!((hit.distance, hit.normal), (2.0, vec3(0.0, 0.0, 1.0)));
+	
+	assert_eq!(ray3(vec3(1.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)).intersect_triangle(t), None);
+	assert_eq!(ray3(vec3(0.25, 0.25, 0.0), vec3(1.0, 0.0, 0.0)).intersect_triangle(t), None);
+	let flat = triangle3(vec3(0.0, 0.0, 2.0), vec3(1.0, 0.0, 2.0), vec3(2.0, 0.0, 2.0));
+	assert_eq!(ray3(vec3(0.5, 0.0, 0.0), vec3(0.0, 0.0, 1.0)).intersect_triangle(flat), None);
+}
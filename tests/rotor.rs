@@ -0,0 +1,62 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec3::*;
+use math_lib::quaternion::*;
+use math_lib::rotor::*;
+use math_lib::angle::*;
+use common::close;
+
+#[test]
+fn bivectors() {
+	let (a, b) = (vec3(1.0, 2.0, -1.0), vec3(0.5, -1.0, 3.0));
+	assert_eq!(wedge(a, b).to_vec3(), cross(a, b));
+	assert_eq!(Bivec3::from_vec3(cross(a, b)), wedge(a, b));
+	assert_eq!(wedge(a, b), -wedge(b, a));
+	assert_eq!(wedge(a, a), Bivec3::zero());
+	assert_eq!(wedge(vec3(1, 0, 0), vec3(0, 1, 0)), bivec3(1, 0, 0));
+	let g = geometric_product(a, b);
+	assert_eq!((g.s, g.b), (dot(a, b), wedge(a, b)));
+}
+
+#[test]
+fn rotors() {
+	let (x, y, z) = (vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+	let r = Rotor3::from_rotation_arc(x, y);
+	assert!(close(r.rotate(x), y));
+	assert!(close(r * z, z));
+	assert!(close(r.reverse().rotate(y), x));
+	let p = Rotor3::from_plane_angle(wedge(x, y), Rad(std::f64::consts::FRAC_PI_2));
+	assert!((p.s - r.s).abs() < 1e-12 && (p.b - r.b).magnitude() < 1e-12);
+	let flip = Rotor3::from_rotation_arc(x, -x);
+	assert!(close(flip.rotate(x), -x));
+	
+	let (a, b) = (vec3(0.3, -1.0, 2.0), vec3(-2.0, 0.5, 0.25));
+	let q = Rotor3::from_rotation_arc(a, b);
+	assert!(close(q.rotate(a).normalize(), b.normalize()));
+	//the geometric product ba rotates a towards b by twice their angle
+	let twice = geometric_product(b, a).normalise();
+	assert!(close(twice.rotate(a).normalize(), (q * q).rotate(a).normalize()));
+	assert!(close((q * r).rotate(a), q.rotate(r.rotate(a))));
+	
+	let half = q.pow(0.5);
+	assert!(close((half * half).rotate(a), q.rotate(a)));
+	let e = q.ln().exp();
+	assert!((e.s - q.s).abs() < 1e-12 && (e.b - q.b).magnitude() < 1e-12);
+	assert_eq!(Rotor3::<f64>::ident().ln(), Bivec3::zero());
+}
+
+#[test]
+fn quaternion_conversion() {
+	let q = Quaternion::from_axis_angle(vec3(1.0, 2.0, 3.0).normalize(), Rad(0.8));
+	let r = Rotor3::from(q);
+	assert_eq!(Quaternion::from(r), q);
+	let v = vec3(0.5, -2.0, 1.0);
+	assert!(close(r.rotate(v), q.rotate(v)));
+	let q2 = Quaternion::from_axis_angle(vec3(0.0, 1.0, 0.0), Rad(-1.3));
+	assert_eq!(Rotor3::from_quaternion(q * q2), r * Rotor3::from_quaternion(q2));
+	assert!(close(Rotor3::from_rotation_arc(v, vec3(1.0, 1.0, 0.0)).rotate(v), Quaternion::from_rotation_arc(v, vec3(1.0, 1.0, 0.0)).rotate(v)));
+	let m = r.rot_mat();
+	assert!(close(m.apply_to(v), q.rotate(v)));
+}
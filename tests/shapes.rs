@@ -0,0 +1,70 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec3::*;
+use math_lib::mat3::*;
+use math_lib::shapes::*;
+use common::close;
+
+#[test]
+fn plane_and_sphere() {
+	let p = Plane::from_points(vec3(0.0, 2.0, 0.0), vec3(0.0, 2.0, 1.0), vec3(1.0, 2.0, 0.0));
+	assert_eq!(p, plane(vec3(0.0, 1.0, 0.0), -2.0));
+	assert_eq!(p.signed_distance(vec3(5.0, -1.0, 3.0)), -3.0);
+	assert_eq!(p.distance_to_point(vec3(5.0, -1.0, 3.0)), 3.0);
+	assert_eq!(p.closest_point(vec3(5.0, -1.0, 3.0)), vec3(5.0, 2.0, 3.0));
+	assert_eq!(plane(vec3(0.0, 2.0, 0.0), -4.0).normalize(), p);
+	
+	let s = sphere(vec3(1.0, 0.0, 0.0), 2.0);
+	assert_eq!(s.signed_distance(vec3(1.0, 0.0, 5.0)), 3.0);
+	assert_eq!(s.signed_distance(vec3(1.0, 0.5, 0.0)), -1.5);
+	assert_eq!(s.distance_to_point(vec3(1.0, 0.5, 0.0)), 0.0);
+	assert_eq!(s.closest_point(vec3(1.0, 0.0, 5.0)), vec3(1.0, 0.0, 2.0));
+	assert_eq!(s.closest_point(vec3(1.0, 0.5, 0.0)), vec3(1.0, 0.5, 0.0));
+}
+
+#[test]
+fn segment_and_capsule() {
+	let s = segment3(vec3(0.0, 0.0, 0.0), vec3(4.0, 0.0, 0.0));
+	assert_eq!(s.closest_point(vec3(1.0, 3.0, 0.0)), vec3(1.0, 0.0, 0.0));
+	assert_eq!(s.closest_point(vec3(-2.0, 1.0, 0.0)), vec3(0.0, 0.0, 0.0));
+	assert_eq!(s.closest_param(vec3(9.0, 1.0, 0.0)), 1.0);
+	assert_eq!(s.distance_to_point(vec3(7.0, 4.0, 0.0)), 5.0);
+	assert_eq!(segment3(Vec3::<f64>::one(), Vec3::one()).closest_point(Vec3::zero()), Vec3::one());
+	
+	let c = capsule(s.a, s.b, 1.0);
+	assert_eq!(c.signed_distance(vec3(2.0, 3.0, 0.0)), 2.0);
+	assert_eq!(c.signed_distance(vec3(2.0, 0.5, 0.0)), -0.5);
+	assert_eq!(c.closest_point(vec3(7.0, 0.0, 0.0)), vec3(5.0, 0.0, 0.0));
+	assert_eq!(c.distance_to_point(vec3(2.0, 0.0, 0.0)), 0.0);
+}
+
+#[test]
+fn triangle() {
+	let t = triangle3(vec3(0.0, 0.0, 0.0), vec3(2.0, 0.0, 0.0), vec3(0.0, 2.0, 0.0));
+	assert_eq!(t.normal(), vec3(0.0, 0.0, 1.0));
+	assert_eq!(t.area(), 2.0);
+	assert!(close(t.barycentric(vec3(0.5, 0.5, 7.0)), vec3(0.5, 0.25, 0.25)));
+	
+	//face, vertex and edge regions
+	assert_eq!(t.closest_point(vec3(0.5, 0.5, 3.0)), vec3(0.5, 0.5, 0.0));
+	assert_eq!(t.closest_point(vec3(-1.0, -1.0, 0.0)), vec3(0.0, 0.0, 0.0));
+	assert_eq!(t.closest_point(vec3(3.0, -1.0, 0.0)), vec3(2.0, 0.0, 0.0));
+	assert_eq!(t.closest_point(vec3(1.0, -1.0, 1.0)), vec3(1.0, 0.0, 0.0));
+	assert!(close(t.closest_point(vec3(2.0, 2.0, 0.0)), vec3(1.0, 1.0, 0.0)));
+	
+	assert_eq!(t.signed_distance(vec3(0.5, 0.5, -3.0)), -3.0);
+	assert_eq!(t.distance_to_point(vec3(0.5, 0.5, -3.0)), 3.0);
+}
+
+#[test]
+fn obb() {
+	//a 4 x 2 x 2 box turned a quarter about z, so its long side runs along y
+	let b = obb3(vec3(0.0, 0.0, 1.0), mat3(vec3(0.0, -1.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(0.0, 0.0, 1.0)), vec3(2.0, 1.0, 1.0));
+	assert!(close(b.closest_point(vec3(0.0, 5.0, 1.0)), vec3(0.0, 2.0, 1.0)));
+	assert!(close(b.closest_point(vec3(3.0, 0.0, 1.0)), vec3(1.0, 0.0, 1.0)));
+	assert!((b.distance_to_point(vec3(0.0, 5.0, 1.0)) - 3.0).abs() < 1e-9);
+	assert!((b.signed_distance(vec3(0.0, 1.5, 1.0)) + 0.5).abs() < 1e-9);
+	assert_eq!(b.closest_point(vec3(0.5, 0.5, 1.5)), vec3(0.5, 0.5, 1.5));
+}
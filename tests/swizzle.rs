@@ -0,0 +1,31 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+
+#[test]
+fn read_swizzles() {
+	let v = vec4(1, 2, 3, 4);
+	assert_eq!(v.wzyx(), vec4(4, 3, 2, 1));
+	assert_eq!(v.xxyy(), vec4(1, 1, 2, 2));
+	assert_eq!(v.zyx(), vec3(3, 2, 1));
+	assert_eq!(v.wx(), vec2(4, 1));
+	let v = vec3(1, 2, 3);
+	assert_eq!(v.zxy(), vec3(3, 1, 2));
+	assert_eq!(v.xyzx(), vec4(1, 2, 3, 1));
+	assert_eq!(vec2(1, 2).yxyx(), vec4(2, 1, 2, 1));
+}
+
+#[test]
+fn write_swizzles() {
+	let mut v = vec3(1, 2, 3);
+	v.set_zx(vec2(7, 8));
+	assert_eq!(v, vec3(8, 2, 7));
+	let mut v = vec4(1, 2, 3, 4);
+	v.set_wzy(vec3(5, 6, 7));
+	assert_eq!(v, vec4(1, 7, 6, 5));
+	let mut v = vec2(1, 2);
+	v.set_yx(vec2(3, 4));
+	assert_eq!(v, vec2(4, 3));
+}
@@ -0,0 +1,92 @@
+extern crate math_lib;
+
+mod common;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::mat2::*;
+use math_lib::mat3::*;
+use math_lib::mat4::*;
+use math_lib::quaternion::*;
+use math_lib::transform::*;
+use math_lib::angle::*;
+use common::close;
+
+fn same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) -> bool {
+	(a.dot(b).abs() - 1.0).abs() < 1e-9
+}
+
+#[test]
+fn decompose() {
+	let r = Quaternion::from_axis_angle(vec3(1.0, 2.0, -0.5).normalize(), Rad(0.8));
+	let (t, s) = (vec3(1.0, -2.0, 3.0), vec3(2.0, 0.5, 3.0));
+	let m = Mat4::from_trs(t, r, s);
+	let p = vec3(0.3, -1.0, 2.0);
+	assert!(close(m.transform_point3(p), r.rotate(p * s) + t));
+	let (t2, r2, s2) = m.decompose().unwrap();
+	assert!(close(t2, t) && close(s2, s) && same_rotation(r2, r));
+	
+	//mirrored, comes back with a negative x scale that gives the same matrix
+	let m = Mat4::from_trs(t, r, vec3(2.0, -0.5, 3.0));
+	let (t2, r2, s2) = m.decompose().unwrap();
+	assert!(s2.x < 0.0);
+	assert!(close(Mat4::from_trs(t2, r2, s2).transform_point3(p), m.transform_point3(p)));
+	
+	let shear = mat4(vec4(1.0, 0.5, 0.0, 0.0), vec4(0.0, 1.0, 0.0, 0.0), vec4(0.0, 0.0, 1.0, 0.0), vec4(0.0, 0.0, 0.0, 1.0));
+	assert_eq!(shear.decompose(), None);
+	let projection = Mat4::perspective(1.0, 1.0, 0.1, 10.0, DepthRange::ZeroToOne);
+	assert_eq!(projection.decompose(), None);
+	assert_eq!(Mat4::scale(vec3(1.0, 0.0, 1.0)).decompose(), None);
+}
+
+#[test]
+fn transforms() {
+	let a = transform3(vec3(1.0, 0.0, 0.0), Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(0.5)), vec3(2.0, 2.0, 2.0));
+	let b = transform3(vec3(0.0, 3.0, -1.0), Quaternion::from_axis_angle(vec3(1.0, 0.0, 0.0), Rad(1.2)), vec3(1.0, 0.5, 2.0));
+	let p = vec3(0.5, -1.0, 4.0);
+	assert!(close(a.compose(b).transform_point(p), a.transform_point(b.transform_point(p))));
+	assert!(close((a * b).to_mat4().transform_point3(p), (a.to_mat4() * b.to_mat4()).transform_point3(p)));
+	assert!(close(a.inverse().transform_point(a.transform_point(p)), p));
+	assert!(close(Transform3::from_mat4(b.to_mat4()).unwrap().transform_point(p), b.transform_point(p)));
+	
+	let half = Transform3::ident().lerp(a, 0.5);
+	assert!(close(half.translation, vec3(0.5, 0.0, 0.0)) && close(half.scale, vec3(1.5, 1.5, 1.5)));
+	assert!(same_rotation(half.rotation, Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Rad(0.25))));
+	assert_eq!(Transform3::<f64>::default(), Transform3::ident());
+}
+
+#[test]
+fn affine() {
+	let a = affine2(mat2(vec2(2.0, 1.0), vec2(0.0, 3.0)), vec2(1.0, -1.0));
+	let b = affine2(Mat2::rotate(Rad(0.3)), vec2(0.5, 2.0));
+	let p = vec2(1.5, -0.5);
+	assert_eq!(a.transform_point(p), vec2(3.5, -2.5));
+	assert_eq!(a.transform_vector(p), vec2(2.5, -1.5));
+	assert!(((a * b).transform_point(p) - a.transform_point(b.transform_point(p))).magnitude() < 1e-12);
+	assert!((a.inverse().transform_point(a.transform_point(p)) - p).magnitude() < 1e-12);
+	assert_eq!(Affine2::from_mat3(a.to_mat3()), a);
+	assert_eq!(a.to_mat3().apply_to(p.extend(1.0)), a.transform_point(p).extend(1.0));
+	assert_eq!(affine2(Mat2::zero(), vec2(1.0, 1.0)).try_inverse(), None);
+	
+	let m = mat3(vec3(1.0, 2.0, 0.0), vec3(0.0, 1.0, 3.0), vec3(4.0, 0.0, 1.0));
+	let a = affine3(m, vec3(1.0, 2.0, 3.0));
+	let b = affine3(Mat3::rotate_x(Rad(0.7)), vec3(-1.0, 0.0, 0.5));
+	let p = vec3(0.5, -1.0, 4.0);
+	assert!(close((a * b).transform_point(p), a.transform_point(b.transform_point(p))));
+	assert!(close(a.inverse().transform_point(a.transform_point(p)), p));
+	assert!(close(a.try_inverse().unwrap().to_mat4().transform_point3(p), a.to_mat4().inv().transform_point3(p)));
+	assert_eq!(Affine3::from_mat4(a.to_mat4()), a);
+	assert_eq!(Affine3::<f64>::default(), Affine3::ident());
+}
+
+#[test]
+fn isometry() {
+	let a = isometry3(Quaternion::from_axis_angle(vec3(0.0, 1.0, 0.0), Rad(0.9)), vec3(1.0, 2.0, 3.0));
+	let b = isometry3(Quaternion::from_axis_angle(vec3(1.0, 0.0, 0.0), Rad(-0.4)), vec3(0.0, -1.0, 0.0));
+	let p = vec3(0.5, -1.0, 4.0);
+	assert!(close((a * b).transform_point(p), a.transform_point(b.transform_point(p))));
+	assert!(close(a.inverse().transform_point(a.transform_point(p)), p));
+	assert!(close(a.to_mat4().transform_point3(p), a.transform_point(p)));
+	assert!(close(a.to_affine3().transform_vector(p), a.transform_vector(p)));
+}
@@ -0,0 +1,99 @@
+extern crate math_lib;
+
+use math_lib::vec2::*;
+use math_lib::vec3::*;
+use math_lib::vec4::*;
+use math_lib::angle::*;
+
+use std::f64::consts::PI;
+
+fn close(a: f64, b: f64) -> bool {
+	(a - b).abs() < 1e-12
+}
+
+fn close3(a: Vec3<f64>, b: Vec3<f64>) -> bool {
+	close(a.x, b.x) && close(a.y, b.y) && close(a.z, b.z)
+}
+
+#[test]
+fn angles() {
+	let Rad(a) = vec3(1.0, 0.0, 0.0).angle_between(vec3(0.0, 3.0, 0.0));
+	assert!(close(a, PI / 2.0));
+	assert!(close(vec4(1.0, 0.0, 0.0, 0.0).angle_between(vec4(-2.0, 0.0, 0.0, 0.0)).0, PI));
+	assert!(close(angle_between(vec2(1.0, 0.0), vec2(1.0, 1.0)).0, PI / 4.0));
+	
+	//acos of the dot product gives 0 or nan for this, the true angle is about 1e-9
+	let Rad(tiny) = vec3(1.0, 0.0, 0.0).angle_between(vec3(1.0, 1e-9f64, 0.0));
+	assert!((tiny - 1e-9).abs() < 1e-20);
+	assert_eq!(vec3(1.0, 2.0, 3.0).angle_between(vec3(1.0, 2.0, 3.0)), Rad(0.0));
+	
+	assert!(close(vec2(1.0, 0.0).signed_angle_between(vec2(0.0, 1.0)).0, PI / 2.0));
+	assert!(close(vec2(1.0, 0.0).signed_angle_between(vec2(0.0, -1.0)).0, -PI / 2.0));
+	
+	let (x, y, z) = (vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+	assert!(close(x.signed_angle_around(y, z).0, PI / 2.0));
+	assert!(close(x.signed_angle_around(y, -z).0, -PI / 2.0));
+}
+
+#[test]
+fn projection_and_reflection() {
+	let v = vec3(2.0, 3.0, 4.0);
+	let n = vec3(0.0, 2.0, 0.0);
+	assert_eq!(v.project_onto(n), vec3(0.0, 3.0, 0.0));
+	assert_eq!(v.reject_from(n), vec3(2.0, 0.0, 4.0));
+	assert_eq!(vec2(1.0, -1.0).reflect(vec2(0.0, 1.0)), vec2(1.0, 1.0));
+	assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).project_onto(vec4(0.0, 0.0, 0.0, 1.0)), vec4(0.0, 0.0, 0.0, 4.0));
+	
+	let up = vec3(0.0, 1.0, 0.0);
+	let d = vec3(1.0, -1.0, 0.0).normalize();
+	//the same medium passes straight through
+	assert!(close3(d.refract(up, 1.0).unwrap(), d));
+	//into a denser medium bends towards the normal, following snell's law
+	let r = d.refract(up, 1.0 / 1.5).unwrap();
+	let sin_in = d.reject_from(up).magnitude();
+	let sin_out = r.reject_from(up).magnitude();
+	assert!(close(sin_in / sin_out, 1.5));
+	assert!(close(r.magnitude(), 1.0));
+	//out of it at a grazing angle is totally reflected
+	assert_eq!(d.refract(up, 1.5), None);
+}
+
+#[test]
+fn slerp() {
+	let (x, y) = (vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0));
+	let h = (0.5f64).sqrt();
+	assert!(close3(x.slerp(y, 0.5), vec3(h, h, 0.0)));
+	assert!(close3(x.slerp(y, 0.0), x));
+	assert!(close3(x.slerp(y, 1.0), y));
+	let third = x.slerp(y, 1.0 / 3.0);
+	assert!(close(third.magnitude(), 1.0));
+	assert!(close(x.angle_between(third).0, PI / 6.0));
+	assert_eq!(x.slerp(x, 0.25), x);
+	
+	let v = vec2(1.0, 0.0).slerp(vec2(0.0, -1.0), 0.5);
+	assert!(close(v.x, h) && close(v.y, -h));
+}
+
+#[test]
+fn slerp_opposite() {
+	//no single arc, 2d turns counter clockwise and 3d and 4d through some perpendicular, all at unit length
+	let (a, b) = (vec2(0.6, 0.8), vec2(-0.6, -0.8));
+	let v = a.slerp(b, 0.3);
+	assert!(close(v.magnitude(), 1.0));
+	assert!(close(a.signed_angle_between(v).0, 0.3 * PI));
+	assert!(close(a.slerp(b, 0.5).x, -0.8) && close(a.slerp(b, 0.5).y, 0.6));
+	let end = a.slerp(b, 1.0);
+	assert!(close(end.x, b.x) && close(end.y, b.y));
+	
+	let x = vec3(1.0, 0.0, 0.0);
+	let v = x.slerp(-x, 0.3);
+	assert!(close(v.magnitude(), 1.0));
+	assert!(close(x.angle_between(v).0, 0.3 * PI));
+	assert!(close3(x.slerp(-x, 1.0), -x));
+	
+	let w = vec4(0.5, -0.5, 0.5, 0.5);
+	let v = w.slerp(-w, 0.5);
+	assert!(close(v.magnitude(), 1.0));
+	assert!(close(dot(w, v), 0.0));
+	assert!(close(w.angle_between(w.slerp(-w, 0.3)).0, 0.3 * PI));
+}